@@ -0,0 +1,240 @@
+use crate::challenges::Solution;
+use crate::helpers::{day_dir, root_path};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+
+pub const ANSWERS_PATH: &str = root_path!("/answers.toml");
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Expected {
+    pub easy: Option<String>,
+    pub hard: Option<String>,
+}
+
+impl Expected {
+    pub fn for_sample(year: usize, day: usize) -> Option<Self> {
+        let contents =
+            fs::read_to_string(format!("{}/sample.expected", day_dir(year, day))).ok()?;
+        Some(Self::parse(&contents))
+    }
+
+    fn parse(contents: &str) -> Self {
+        let mut expected = Expected::default();
+        contents
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| line.split_once(':'))
+            .for_each(|(part, value)| {
+                let value = Some(value.trim().to_string());
+                match part.trim() {
+                    "easy" => expected.easy = value,
+                    "hard" => expected.hard = value,
+                    _ => {}
+                }
+            });
+        expected
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct AnswerStore {
+    path: String,
+    days: BTreeMap<(usize, usize), Expected>,
+}
+
+impl AnswerStore {
+    pub fn load(path: &str) -> io::Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        Ok(AnswerStore {
+            path: path.to_string(),
+            days: Self::parse(&contents),
+        })
+    }
+
+    fn parse(contents: &str) -> BTreeMap<(usize, usize), Expected> {
+        let mut days: BTreeMap<(usize, usize), Expected> = BTreeMap::new();
+        let mut day = None;
+        for line in contents.lines().map(|line| line.trim()) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some(table) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                day = table.split_once(".day_").and_then(|(year, day)| {
+                    Some((year.parse::<usize>().ok()?, day.parse::<usize>().ok()?))
+                });
+                continue;
+            }
+            let (Some(day), Some((part, value))) = (day, line.split_once('=')) else {
+                continue;
+            };
+            let value = value.trim();
+            let value = value
+                .strip_prefix('"')
+                .and_then(|value| value.strip_suffix('"'))
+                .map_or_else(|| value.to_string(), unescape);
+            let expected = days.entry(day).or_default();
+            match part.trim() {
+                "easy" => expected.easy = Some(value),
+                "hard" => expected.hard = Some(value),
+                _ => {}
+            }
+        }
+        days
+    }
+
+    pub fn expected(&self, year: usize, day: usize) -> Expected {
+        self.days.get(&(year, day)).cloned().unwrap_or_default()
+    }
+
+    pub fn record(&mut self, year: usize, day: usize, part: u8, answer: &Solution) -> bool {
+        let Some(value) = answer.value() else {
+            return false;
+        };
+        let value = Some(value.to_string());
+        let expected = self.days.entry((year, day)).or_default();
+        match part {
+            1 => expected.easy = value,
+            _ => expected.hard = value,
+        }
+        true
+    }
+
+    pub fn save(&self) -> io::Result<()> {
+        fs::write(&self.path, self.to_toml())
+    }
+
+    fn to_toml(&self) -> String {
+        let mut contents =
+            String::from("# Accepted answers for the checked-in inputs, updated with `--record`\n");
+        for ((year, day), expected) in &self.days {
+            contents.push_str(&format!("\n[{year}.day_{day:02}]\n"));
+            for (key, value) in [("easy", &expected.easy), ("hard", &expected.hard)] {
+                if let Some(value) = value {
+                    contents.push_str(&format!("{key} = \"{}\"\n", escape(value)));
+                }
+            }
+        }
+        contents
+    }
+}
+
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn unescape(value: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next() {
+                Some(next @ ('"' | '\\')) => unescaped.push(next),
+                Some(next) => unescaped.extend([c, next]),
+                None => unescaped.push(c),
+            },
+            _ => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Verdict {
+    Pass,
+    Fail(String),
+    Missing,
+}
+
+pub fn check(answer: &Solution, expected: Option<&String>) -> Verdict {
+    let value = answer.value().map(|value| value.to_string());
+    match expected {
+        Some(expected) if value.as_ref() == Some(expected) => Verdict::Pass,
+        Some(expected) => Verdict::Fail(expected.clone()),
+        None => Verdict::Missing,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::{check, AnswerStore, Expected, Verdict};
+    use crate::challenges::Solution;
+
+    #[test]
+    fn test_parse() {
+        let expected = Expected::parse("# comment\neasy: 3\n\nhard:  4174379265 \n");
+        assert_eq!(expected.easy, Some("3".to_string()));
+        assert_eq!(expected.hard, Some("4174379265".to_string()));
+        let expected = Expected::parse("hard: 6");
+        assert_eq!(expected.easy, None);
+    }
+
+    #[test]
+    fn test_check() {
+        let expected = Some("3".to_string());
+        let zeros = |count: u64| Solution::labelled("Zeros", count);
+        assert_eq!(check(&zeros(3), expected.as_ref()), Verdict::Pass);
+        assert!(matches!(
+            check(&zeros(4), expected.as_ref()),
+            Verdict::Fail(_)
+        ));
+        assert!(matches!(
+            check(&Solution::Unsolved, expected.as_ref()),
+            Verdict::Fail(_)
+        ));
+        assert_eq!(check(&zeros(3), None), Verdict::Missing);
+    }
+
+    #[test]
+    fn test_answer_store_round_trip() {
+        let mut store = AnswerStore::default();
+        assert!(store.record(2025, 5, 2, &Solution::labelled("Total Unspoiled", 14)));
+        assert!(store.record(2025, 1, 1, &Solution::labelled("Zeros", 3)));
+        assert!(store.record(2024, 1, 1, &Solution::labelled("Distance", 11)));
+        assert!(!store.record(2025, 9, 1, &Solution::Unsolved));
+        let toml = store.to_toml();
+        assert!(toml.contains("[2024.day_01]\neasy = \"11\"\n\n[2025.day_01]"));
+        assert!(toml.contains("[2025.day_01]\neasy = \"3\"\n"));
+        assert!(toml.contains("[2025.day_05]\nhard = \"14\"\n"));
+        assert!(!toml.contains("day_09"));
+
+        let days = AnswerStore::parse(&toml);
+        assert_eq!(days[&(2025, 1)].easy, Some("3".to_string()));
+        assert_eq!(days[&(2025, 1)].hard, None);
+        assert_eq!(days[&(2025, 5)].hard, Some("14".to_string()));
+        assert_eq!(days[&(2024, 1)].easy, Some("11".to_string()));
+    }
+
+    #[test]
+    fn test_answer_store_quoted_answer() {
+        let mut store = AnswerStore::default();
+        let answer = r#"say "hi" \o/"#;
+        assert!(store.record(2025, 2, 1, &Solution::labelled("Message", answer)));
+        let toml = store.to_toml();
+        assert!(toml.contains(r#"easy = "say \"hi\" \\o/""#));
+        let days = AnswerStore::parse(&toml);
+        assert_eq!(days[&(2025, 2)].easy, Some(answer.to_string()));
+    }
+
+    #[test]
+    fn test_answer_store_parse() {
+        let days = AnswerStore::parse(
+            "# header\n[2025.day_03]\neasy = \"357\"\nhard=\"12\"\n[other]\neasy = \"1\"\n",
+        );
+        assert_eq!(days.len(), 1);
+        assert_eq!(days[&(2025, 3)].easy, Some("357".to_string()));
+        assert_eq!(days[&(2025, 3)].hard, Some("12".to_string()));
+    }
+
+    #[test]
+    fn test_sample_files_parse() {
+        let expected = Expected::for_sample(2025, 1).unwrap();
+        assert_eq!(expected.easy, Some("3".to_string()));
+        assert_eq!(expected.hard, Some("6".to_string()));
+    }
+}
@@ -0,0 +1,130 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bench {
+    pub iterations: usize,
+    pub warmup: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Stats {
+    pub iterations: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+    pub outliers: Vec<Outlier>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Outlier {
+    pub iteration: usize,
+    pub duration: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let nanos = samples
+            .iter()
+            .map(|d| d.as_nanos() as f64)
+            .collect::<Vec<f64>>();
+        let mut sorted = nanos.clone();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+
+        let n = sorted.len() as f64;
+        let mean = sorted.iter().sum::<f64>() / n;
+        let variance = if sorted.len() > 1 {
+            sorted.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0)
+        } else {
+            0.0
+        };
+        let p95_rank = ((0.95 * n).ceil() as usize).clamp(1, sorted.len());
+
+        let q1 = quantile(&sorted, 0.25);
+        let q3 = quantile(&sorted, 0.75);
+        let fence = 1.5 * (q3 - q1);
+        let outliers = nanos
+            .iter()
+            .enumerate()
+            .filter(|(_, x)| **x < q1 - fence || **x > q3 + fence)
+            .map(|(i, _)| Outlier {
+                iteration: i + 1,
+                duration: samples[i],
+            })
+            .collect();
+
+        Stats {
+            iterations: samples.len(),
+            min: to_duration(sorted[0]),
+            median: to_duration(quantile(&sorted, 0.5)),
+            mean: to_duration(mean),
+            p95: to_duration(sorted[p95_rank - 1]),
+            stddev: to_duration(variance.sqrt()),
+            outliers,
+        }
+    }
+}
+
+fn quantile(sorted: &[f64], q: f64) -> f64 {
+    let position = q * (sorted.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+fn to_duration(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos.round() as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::{quantile, Outlier, Stats};
+    use std::time::Duration;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_micros(*x)).collect()
+    }
+
+    #[test]
+    fn test_quantile() {
+        let sorted = vec![1.0, 2.0, 3.0, 4.0];
+        assert_eq!(quantile(&sorted, 0.0), 1.0);
+        assert_eq!(quantile(&sorted, 0.5), 2.5);
+        assert_eq!(quantile(&sorted, 1.0), 4.0);
+    }
+
+    #[test]
+    fn test_single_sample() {
+        let stats = Stats::from_samples(&micros(&[7]));
+        assert_eq!(stats.iterations, 1);
+        assert_eq!(stats.min, Duration::from_micros(7));
+        assert_eq!(stats.median, Duration::from_micros(7));
+        assert_eq!(stats.p95, Duration::from_micros(7));
+        assert_eq!(stats.stddev, Duration::ZERO);
+        assert!(stats.outliers.is_empty());
+    }
+
+    #[test]
+    fn test_stats() {
+        let stats = Stats::from_samples(&micros(&[5, 1, 3, 2, 4]));
+        assert_eq!(stats.min, Duration::from_micros(1));
+        assert_eq!(stats.median, Duration::from_micros(3));
+        assert_eq!(stats.mean, Duration::from_micros(3));
+        assert_eq!(stats.p95, Duration::from_micros(5));
+        assert_eq!(stats.stddev, Duration::from_nanos(1581));
+        assert!(stats.outliers.is_empty());
+    }
+
+    #[test]
+    fn test_outliers() {
+        let stats = Stats::from_samples(&micros(&[10, 11, 10, 12, 90, 11, 10, 11]));
+        assert_eq!(
+            stats.outliers,
+            vec![Outlier {
+                iteration: 5,
+                duration: Duration::from_micros(90)
+            }]
+        );
+    }
+}
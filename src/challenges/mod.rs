@@ -1,18 +1,294 @@
-pub mod day_01;
-pub mod day_02;
-pub mod day_03;
-pub mod day_04;
-pub mod day_05;
-pub mod day_06;
-pub mod day_07;
-pub mod day_08;
-pub mod day_09;
-pub mod day_10;
-pub mod day_11;
-pub mod day_12;
-
-pub trait Challenge {
+use crate::helpers::Cancellation;
+use crate::parse::ParseError;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+
+macro_rules! days {
+    ($($module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub fn registrations() -> Vec<Registration> {
+            vec![$($module::REGISTRATION),*]
+        }
+    };
+}
+
+macro_rules! years {
+    ($($module:ident),* $(,)?) => {
+        $(pub mod $module;)*
+
+        pub fn registry() -> Result<Registry, RegistryError> {
+            Registry::new([$($module::registrations()),*].concat())
+        }
+    };
+}
+
+years! {
+    year_2025,
+}
+
+pub type Constructor = fn(&str) -> Result<Box<dyn Challenge>, ParseError>;
+
+pub trait Challenge: Send {
     fn preamble(&self) -> String;
-    fn run_easy(&mut self) -> String;
-    fn run_hard(&mut self) -> String;
+    fn run_easy(&mut self, cancel: &Cancellation) -> Solution;
+    fn run_hard(&mut self, cancel: &Cancellation) -> Solution;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Int(i64),
+    BigInt(i128),
+    Str(String),
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Value::Int(value) => write!(f, "{value}"),
+            Value::BigInt(value) => write!(f, "{value}"),
+            Value::Str(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+macro_rules! int_values {
+    ($($int:ty),* $(,)?) => {
+        $(impl From<$int> for Value {
+            fn from(value: $int) -> Self {
+                match i64::try_from(value) {
+                    Ok(value) => Value::Int(value),
+                    Err(_) => Value::BigInt(value as i128),
+                }
+            }
+        })*
+    };
+}
+
+int_values!(i32, u32, i64, u64, usize, i128);
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::Str(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::Str(value.to_string())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Solution {
+    Solved {
+        value: Value,
+        label: Option<&'static str>,
+    },
+    Unsolved,
+}
+
+impl Solution {
+    pub fn labelled(label: &'static str, value: impl Into<Value>) -> Self {
+        Solution::Solved {
+            value: value.into(),
+            label: Some(label),
+        }
+    }
+
+    pub fn value(&self) -> Option<&Value> {
+        match self {
+            Solution::Solved { value, .. } => Some(value),
+            Solution::Unsolved => None,
+        }
+    }
+
+    pub fn label(&self) -> Option<&'static str> {
+        match self {
+            Solution::Solved { label, .. } => *label,
+            Solution::Unsolved => None,
+        }
+    }
+
+    pub fn is_solved(&self) -> bool {
+        matches!(self, Solution::Solved { .. })
+    }
+}
+
+impl Display for Solution {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Solution::Solved {
+                value,
+                label: Some(label),
+            } => write!(f, "{label}: {value}"),
+            Solution::Solved { value, label: None } => write!(f, "{value}"),
+            Solution::Unsolved => write!(f, "No Result"),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+pub struct Registration {
+    pub year: usize,
+    pub day: usize,
+    pub title: &'static str,
+    pub constructor: Constructor,
+}
+
+pub struct Registry {
+    days: BTreeMap<(usize, usize), Registration>,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RegistryError(String);
+
+impl Display for RegistryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Registry {
+    pub fn new(registrations: Vec<Registration>) -> Result<Self, RegistryError> {
+        let mut days: BTreeMap<(usize, usize), Registration> = BTreeMap::new();
+        for registration in registrations {
+            let Registration {
+                year, day, title, ..
+            } = registration;
+            if year < 2015 {
+                return Err(RegistryError(format!(
+                    "{title} is registered for {year}, Advent of Code started in 2015"
+                )));
+            }
+            if !(1..=25).contains(&day) {
+                return Err(RegistryError(format!(
+                    "{title} is registered as day {day} of {year}, expected 1 to 25"
+                )));
+            }
+            if let Some(existing) = days.insert((year, day), registration) {
+                return Err(RegistryError(format!(
+                    "Day {day} of {year} is registered twice, by {} and {title}",
+                    existing.title
+                )));
+            }
+        }
+        Ok(Registry { days })
+    }
+
+    pub fn get(&self, year: usize, day: usize) -> Option<&Registration> {
+        self.days.get(&(year, day))
+    }
+
+    pub fn days(&self) -> Vec<(usize, usize)> {
+        self.days.keys().copied().collect()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Registration> {
+        self.days.values()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::challenges::{registry, Challenge, Registration, Registry, Solution, Value};
+    use crate::helpers::Cancellation;
+
+    struct Named(&'static str);
+
+    impl Challenge for Named {
+        fn preamble(&self) -> String {
+            self.0.to_string()
+        }
+
+        fn run_easy(&mut self, _cancel: &Cancellation) -> Solution {
+            Solution::Unsolved
+        }
+
+        fn run_hard(&mut self, _cancel: &Cancellation) -> Solution {
+            Solution::Unsolved
+        }
+    }
+
+    fn registration(day: usize, title: &'static str) -> Registration {
+        in_year(2025, day, title)
+    }
+
+    fn in_year(year: usize, day: usize, title: &'static str) -> Registration {
+        Registration {
+            year,
+            day,
+            title,
+            constructor: |_| Ok(Box::new(Named("constructed"))),
+        }
+    }
+
+    #[test]
+    fn test_registry_orders_days_and_allows_gaps() {
+        let registry = Registry::new(vec![
+            registration(7, "Seven"),
+            registration(2, "Two"),
+            registration(4, "Four"),
+        ])
+        .unwrap();
+        assert_eq!(registry.days(), vec![(2025, 2), (2025, 4), (2025, 7)]);
+        assert_eq!(registry.get(2025, 4).unwrap().title, "Four");
+        assert!(registry.get(2025, 3).is_none());
+        assert!(registry.get(2024, 4).is_none());
+        let titles = registry.iter().map(|r| r.title).collect::<Vec<&str>>();
+        assert_eq!(titles, vec!["Two", "Four", "Seven"]);
+        let challenge = (registry.get(2025, 7).unwrap().constructor)("").unwrap();
+        assert_eq!(challenge.preamble(), "constructed");
+    }
+
+    #[test]
+    fn test_registry_rejects_duplicates_and_invalid_days() {
+        assert!(Registry::new(vec![registration(3, "A"), registration(3, "B")]).is_err());
+        assert!(Registry::new(vec![registration(0, "Zero")]).is_err());
+        assert!(Registry::new(vec![registration(26, "Boxing Day")]).is_err());
+        assert!(Registry::new(vec![in_year(2014, 1, "Too Early")]).is_err());
+    }
+
+    #[test]
+    fn test_registry_keys_days_by_year() {
+        let registry = Registry::new(vec![
+            registration(1, "Secret Entrance"),
+            in_year(2024, 1, "Historian Hysteria"),
+            in_year(2024, 25, "Code Chronicle"),
+        ])
+        .unwrap();
+        assert_eq!(registry.days(), vec![(2024, 1), (2024, 25), (2025, 1)]);
+        assert_eq!(registry.get(2024, 1).unwrap().title, "Historian Hysteria");
+        assert_eq!(registry.get(2025, 1).unwrap().title, "Secret Entrance");
+    }
+
+    #[test]
+    fn test_registered_days() {
+        let registry = registry().unwrap();
+        let days = registry
+            .days()
+            .into_iter()
+            .filter(|(year, _)| *year == 2025)
+            .collect::<Vec<_>>();
+        assert_eq!(days, (1..=12).map(|day| (2025, day)).collect::<Vec<_>>());
+        assert_eq!(registry.get(2025, 1).unwrap().title, "Secret Entrance");
+    }
+
+    #[test]
+    fn test_solution() {
+        assert_eq!(Solution::labelled("Zeros", 3).to_string(), "Zeros: 3");
+        let unlabelled = Solution::Solved {
+            value: Value::from("abc"),
+            label: None,
+        };
+        assert_eq!(unlabelled.to_string(), "abc");
+        assert_eq!(Solution::Unsolved.to_string(), "No Result");
+        assert_eq!(Solution::Unsolved.value(), None);
+        assert_eq!(
+            Solution::labelled("Span", 7_usize).value(),
+            Some(&Value::Int(7))
+        );
+        assert_eq!(Value::from(u64::MAX), Value::BigInt(u64::MAX as i128));
+        assert_eq!(Value::from(u64::MAX).to_string(), "18446744073709551615");
+    }
 }
@@ -0,0 +1,242 @@
+use crate::challenges::{Challenge, Registration, Solution};
+use crate::helpers::{Cancellation, Reader};
+use crate::parse::{number, ParseError};
+use std::str::FromStr;
+
+const NAME: &str = "Secret Entrance";
+const DAY: &str = "01";
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2025,
+    day: 1,
+    title: NAME,
+    constructor: |text| Ok(Box::new(text.parse::<State>()?)),
+};
+
+pub struct State {
+    input: Vec<Sequence>,
+    dial_limit: i32,
+    dial_position: i32,
+}
+
+impl FromStr for State {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let input = DialParser { dial_limit: 99 }.parse(Reader::from_text(text))?;
+        Ok(State {
+            input,
+            dial_limit: 99,
+            dial_position: 50,
+        })
+    }
+}
+
+impl Challenge for State {
+    fn preamble(&self) -> String {
+        format!("Day {DAY} - {NAME}")
+    }
+    fn run_easy(&mut self, cancel: &Cancellation) -> Solution {
+        let Answer { zero_count, .. } = do_easy(self, cancel);
+        Solution::labelled("Zeros", zero_count)
+    }
+
+    fn run_hard(&mut self, cancel: &Cancellation) -> Solution {
+        let Answer { zero_count, .. } = do_hard(self, cancel);
+        Solution::labelled("Zeros", zero_count)
+    }
+}
+
+fn do_easy(state: &State, cancel: &Cancellation) -> Answer {
+    let mut zero_count = 0;
+    let dial_limit = state.dial_limit + 1;
+    let mut dial_position = state.dial_position;
+    for sequence in &state.input {
+        if cancel.is_cancelled() {
+            break;
+        }
+        dial_position += sequence.magnitude;
+        dial_position = dial_position.rem_euclid(dial_limit);
+        if dial_position == 0 {
+            zero_count += 1;
+        }
+    }
+    Answer { zero_count }
+}
+
+fn do_hard(state: &State, cancel: &Cancellation) -> Answer {
+    let mut zero_count = 0;
+    let dial_limit = state.dial_limit + 1;
+    let mut dial_position = state.dial_position;
+    for sequence in &state.input {
+        if cancel.is_cancelled() {
+            break;
+        }
+        zero_count += sequence.rollovers;
+        let dial_was_zero = dial_position == 0;
+        dial_position += sequence.magnitude;
+        let rolled_over = dial_position >= dial_limit || dial_position <= 0 && !dial_was_zero;
+        dial_position = dial_position.rem_euclid(dial_limit);
+        if rolled_over {
+            zero_count += 1;
+        }
+    }
+    Answer { zero_count }
+}
+
+struct Answer {
+    zero_count: i32,
+}
+
+struct Sequence {
+    rollovers: i32,
+    magnitude: i32,
+}
+
+struct DialParser {
+    dial_limit: i32,
+}
+
+impl DialParser {
+    fn parse(&self, mut reader: Reader) -> Result<Vec<Sequence>, ParseError> {
+        reader
+            .numbered()
+            .map(|(row, line)| {
+                let rollover_value = self.dial_limit + 1;
+                if !line.starts_with(['L', 'R']) {
+                    return Err(ParseError::at(
+                        row,
+                        &line,
+                        0,
+                        "expected a rotation starting with `L` or `R`",
+                    ));
+                }
+                let value = number::<i32>(row, &line, &line[1..])?;
+                let (magnitude, rollovers) = if value > self.dial_limit {
+                    (
+                        value.rem_euclid(rollover_value),
+                        value.div_euclid(rollover_value),
+                    )
+                } else {
+                    (value, 0)
+                };
+                let magnitude = if line.starts_with('L') {
+                    -magnitude
+                } else {
+                    magnitude
+                };
+                Ok(Sequence {
+                    rollovers,
+                    magnitude,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::challenges::year_2025::day_01::{do_easy, do_hard, DialParser, State, DAY};
+    use crate::challenges::year_2025::sample;
+    use crate::helpers::{Cancellation, Reader};
+
+    #[test]
+    fn test_sample_input_easy() {
+        let state = sample(DAY).parse::<State>().unwrap();
+        let result = do_easy(&state, &Cancellation::new());
+        assert_eq!(result.zero_count, 3);
+    }
+
+    #[test]
+    fn test_sample_input_hard() {
+        let state = sample(DAY).parse::<State>().unwrap();
+        let result = do_hard(&state, &Cancellation::new());
+        assert_eq!(result.zero_count, 6);
+    }
+
+    #[test]
+    fn test_parser() {
+        let parser = DialParser { dial_limit: 99 };
+        let result = parser.parse(Reader::single("L41")).unwrap().pop().unwrap();
+        assert_eq!(result.magnitude, -41);
+        assert_eq!(result.rollovers, 0);
+        let result = parser.parse(Reader::single("R12")).unwrap().pop().unwrap();
+        assert_eq!(result.magnitude, 12);
+        assert_eq!(result.rollovers, 0);
+        let result = parser.parse(Reader::single("R115")).unwrap().pop().unwrap();
+        assert_eq!(result.magnitude, 15);
+        assert_eq!(result.rollovers, 1);
+        let result = parser.parse(Reader::single("R200")).unwrap().pop().unwrap();
+        assert_eq!(result.magnitude, 0);
+        assert_eq!(result.rollovers, 2);
+    }
+
+    #[test]
+    fn test_parser_errors() {
+        let parser = DialParser { dial_limit: 99 };
+        let error = parser
+            .parse(Reader::from_vec(vec!["L10", "X5"]))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = parser.parse(Reader::single("R1O")).err().unwrap();
+        assert_eq!((error.line, error.column), (1, 3));
+        assert_eq!(error.message, "expected a number, found `1O`");
+    }
+
+    #[test]
+    fn test_easy_1() {
+        let state = "R15".parse::<State>().unwrap();
+        let result = do_easy(&state, &Cancellation::new());
+        assert_eq!(result.zero_count, 0);
+    }
+
+    #[test]
+    fn test_easy_2() {
+        let state = "R15\nR35\nR40".parse::<State>().unwrap();
+        let result = do_easy(&state, &Cancellation::new());
+        assert_eq!(result.zero_count, 1);
+    }
+
+    #[test]
+    fn test_easy_3() {
+        let state = "L60".parse::<State>().unwrap();
+        let result = do_easy(&state, &Cancellation::new());
+        assert_eq!(result.zero_count, 0);
+    }
+
+    #[test]
+    fn test_hard_1() {
+        let state = "R15".parse::<State>().unwrap();
+        let result = do_hard(&state, &Cancellation::new());
+        assert_eq!(result.zero_count, 0);
+    }
+
+    #[test]
+    fn test_hard_2() {
+        let state = "R15\nR30\nR45".parse::<State>().unwrap();
+        let result = do_hard(&state, &Cancellation::new());
+        assert_eq!(result.zero_count, 1);
+    }
+
+    #[test]
+    fn test_hard_3() {
+        let state = "L60".parse::<State>().unwrap();
+        let result = do_hard(&state, &Cancellation::new());
+        assert_eq!(result.zero_count, 1);
+    }
+
+    #[test]
+    fn test_hard_4() {
+        let state = "R551\nL10".parse::<State>().unwrap();
+        let result = do_hard(&state, &Cancellation::new());
+        assert_eq!(result.zero_count, 7);
+    }
+
+    #[test]
+    fn test_hard_5() {
+        let state = "L50\nL10".parse::<State>().unwrap();
+        let result = do_hard(&state, &Cancellation::new());
+        assert_eq!(result.zero_count, 1);
+    }
+}
@@ -1,21 +1,28 @@
-use crate::challenges::Challenge;
-use crate::helpers::{Reader, PREFIX};
+use crate::challenges::{Challenge, Registration, Solution};
+use crate::helpers::{Cancellation, Reader};
+use crate::parse::{number, ParseError};
+use std::str::FromStr;
 
 const NAME: &str = "Gift Shop";
 const DAY: &str = "02";
 
+pub const REGISTRATION: Registration = Registration {
+    year: 2025,
+    day: 2,
+    title: NAME,
+    constructor: |text| Ok(Box::new(text.parse::<State>()?)),
+};
+
 pub struct State {
     input: Vec<Range>,
 }
 
-impl State {
-    pub fn new() -> Self
-    where
-        Self: Sized,
-    {
-        let reader = Reader::from_file(format!("{PREFIX}_{DAY}/input.txt").as_str());
-        let input = RangeParser {}.parse(reader);
-        State { input }
+impl FromStr for State {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let input = RangeParser {}.parse(Reader::from_text(text))?;
+        Ok(State { input })
     }
 }
 
@@ -24,22 +31,25 @@ impl Challenge for State {
         format!("Day {DAY} - {NAME}")
     }
 
-    fn run_easy(&mut self) -> String {
-        let Answer { invalid_sum } = do_easy(self);
-        format!("Invalid Sum: {invalid_sum}")
+    fn run_easy(&mut self, cancel: &Cancellation) -> Solution {
+        let Answer { invalid_sum } = do_easy(self, cancel);
+        Solution::labelled("Invalid Sum", invalid_sum)
     }
 
-    fn run_hard(&mut self) -> String {
-        let Answer { invalid_sum } = do_hard(self);
-        format!("Invalid Sum: {invalid_sum}")
+    fn run_hard(&mut self, cancel: &Cancellation) -> Solution {
+        let Answer { invalid_sum } = do_hard(self, cancel);
+        Solution::labelled("Invalid Sum", invalid_sum)
     }
 }
 
-fn do_easy(state: &State) -> Answer {
+fn do_easy(state: &State, cancel: &Cancellation) -> Answer {
     let mut pow_10_lut: Vec<u64> = Vec::with_capacity(10);
     let mut invalid_sum = 0;
     state.input.iter().for_each(|range| {
         for id in range.start..=range.end {
+            if cancel.is_cancelled() {
+                return;
+            }
             let len = id.ilog10() + 1;
             if len % 2 != 0 {
                 continue;
@@ -55,11 +65,14 @@ fn do_easy(state: &State) -> Answer {
     Answer { invalid_sum }
 }
 
-fn do_hard(state: &State) -> Answer {
+fn do_hard(state: &State, cancel: &Cancellation) -> Answer {
     let mut pow_10_lut: Vec<u64> = Vec::with_capacity(10);
     let mut invalid_sum = 0;
     state.input.iter().for_each(|range| {
         for id in range.start..=range.end {
+            if cancel.is_cancelled() {
+                return;
+            }
             let len = id.ilog10() + 1;
             let half_len = len / 2;
             let mut digit_count = 1;
@@ -124,63 +137,76 @@ struct Range {
 struct RangeParser {}
 
 impl RangeParser {
-    fn parse(&self, reader: Reader) -> Vec<Range> {
-        reader
-            .into_iter()
-            .next()
-            .unwrap()
-            .split(",")
+    fn parse(&self, mut reader: Reader) -> Result<Vec<Range>, ParseError> {
+        let Some(line) = reader.next() else {
+            return Err(ParseError::at(1, "", 0, "expected a line of ranges"));
+        };
+        line.split(",")
             .map(|range| {
-                let (start, end) = range.split_once("-").unwrap();
-                let start = start.parse::<u64>().unwrap();
-                let end = end.parse::<u64>().unwrap();
-                Range { start, end }
+                let Some((start, end)) = range.split_once("-") else {
+                    let end = &range[range.len()..];
+                    return Err(ParseError::in_field(1, &line, end, "expected `-`"));
+                };
+                let start = number::<u64>(1, &line, start)?;
+                let end = number::<u64>(1, &line, end)?;
+                Ok(Range { start, end })
             })
-            .collect::<Vec<Range>>()
+            .collect::<Result<Vec<Range>, ParseError>>()
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::challenges::day_02::{
-        do_easy, do_hard, get_n_digits, repeat_nibble, Range, RangeParser, State, DAY,
+    use crate::challenges::year_2025::day_02::{
+        do_easy, do_hard, get_n_digits, repeat_nibble, RangeParser, State, DAY,
     };
-    use crate::helpers::{Reader, PREFIX};
+    use crate::challenges::year_2025::sample;
+    use crate::helpers::{Cancellation, Reader};
 
     #[test]
     fn test_sample_input_easy() {
-        let input = RangeParser {}.parse(Reader::from_file(
-            format!("{PREFIX}_{DAY}/sample.txt").as_str(),
-        ));
-        let state = State { input };
-        let result = do_easy(&state);
+        let state = sample(DAY).parse::<State>().unwrap();
+        let result = do_easy(&state, &Cancellation::new());
         assert_eq!(result.invalid_sum, 1227775554);
     }
 
     #[test]
     fn test_sample_input_hard() {
-        let input = RangeParser {}.parse(Reader::from_file(
-            format!("{PREFIX}_{DAY}/sample.txt").as_str(),
-        ));
-        let state = State { input };
-        let result = do_hard(&state);
+        let state = sample(DAY).parse::<State>().unwrap();
+        let result = do_hard(&state, &Cancellation::new());
         assert_eq!(result.invalid_sum, 4174379265);
     }
 
     #[test]
     fn test_parser() {
         let parser = RangeParser {};
-        let result = parser.parse(Reader::single("11-22")).pop().unwrap();
+        let result = parser
+            .parse(Reader::single("11-22"))
+            .unwrap()
+            .pop()
+            .unwrap();
         assert_eq!(result.start, 11);
         assert_eq!(result.end, 22);
         let result = parser
             .parse(Reader::single("824824821-824824827"))
+            .unwrap()
             .pop()
             .unwrap();
         assert_eq!(result.start, 824824821);
         assert_eq!(result.end, 824824827);
     }
 
+    #[test]
+    fn test_parser_errors() {
+        let parser = RangeParser {};
+        let error = parser.parse(Reader::single("11-22,95115")).err().unwrap();
+        assert_eq!((error.line, error.column), (1, 12));
+        assert_eq!(error.message, "expected `-`");
+        let error = parser.parse(Reader::single("11-22,95-1x5")).err().unwrap();
+        assert_eq!((error.column, error.snippet.as_str()), (11, "11-22,95-1x5"));
+        assert!(parser.parse(Reader::from_vec(vec![])).is_err());
+    }
+
     #[test]
     fn test_get_n_digits() {
         let mut lut = Vec::new();
@@ -209,20 +235,15 @@ mod tests {
 
     #[test]
     fn test_easy_1() {
-        let input = vec![Range { start: 1, end: 15 }];
-        let state = State { input };
-        let result = do_easy(&state);
+        let state = "1-15".parse::<State>().unwrap();
+        let result = do_easy(&state, &Cancellation::new());
         assert_eq!(result.invalid_sum, 11);
     }
 
     #[test]
     fn test_hard_1() {
-        let input = vec![Range {
-            start: 100,
-            end: 125,
-        }];
-        let state = State { input };
-        let result = do_hard(&state);
+        let state = "100-125".parse::<State>().unwrap();
+        let result = do_hard(&state, &Cancellation::new());
         assert_eq!(result.invalid_sum, 111);
     }
 }
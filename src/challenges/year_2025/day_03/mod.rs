@@ -1,22 +1,29 @@
-use crate::challenges::Challenge;
-use crate::helpers::{Reader, PREFIX};
+use crate::challenges::{Challenge, Registration, Solution};
+use crate::helpers::{Cancellation, Reader};
+use crate::parse::ParseError;
 use std::ops::{Index, Range};
+use std::str::FromStr;
 
 const NAME: &str = "Lobby";
 const DAY: &str = "03";
 
+pub const REGISTRATION: Registration = Registration {
+    year: 2025,
+    day: 3,
+    title: NAME,
+    constructor: |text| Ok(Box::new(text.parse::<State>()?)),
+};
+
 pub struct State {
     input: Vec<BatteryBank>,
 }
 
-impl State {
-    pub fn new() -> Self
-    where
-        Self: Sized,
-    {
-        let reader = Reader::from_file(format!("{PREFIX}_{DAY}/input.txt").as_str());
-        let input = BatteryBankParser {}.parse(reader);
-        State { input }
+impl FromStr for State {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let input = BatteryBankParser {}.parse(Reader::from_text(text))?;
+        Ok(State { input })
     }
 }
 
@@ -25,23 +32,26 @@ impl Challenge for State {
         format!("Day {DAY} - {NAME}")
     }
 
-    fn run_easy(&mut self) -> String {
-        let Answer { max_joltage } = sum_joltage(&self, 2);
-        format!("Max Joltage: {max_joltage}")
+    fn run_easy(&mut self, cancel: &Cancellation) -> Solution {
+        let Answer { max_joltage } = sum_joltage(self, 2, cancel);
+        Solution::labelled("Max Joltage", max_joltage)
     }
 
-    fn run_hard(&mut self) -> String {
-        let Answer { max_joltage } = sum_joltage(&self, 12);
-        format!("Max Joltage: {max_joltage}")
+    fn run_hard(&mut self, cancel: &Cancellation) -> Solution {
+        let Answer { max_joltage } = sum_joltage(self, 12, cancel);
+        Solution::labelled("Max Joltage", max_joltage)
     }
 }
 
-fn sum_joltage(state: &State, batteries_per_bank: u8) -> Answer {
+fn sum_joltage(state: &State, batteries_per_bank: u8, cancel: &Cancellation) -> Answer {
     let mut max_joltage = 0;
-    state.input.iter().for_each(|battery_bank| {
+    for battery_bank in &state.input {
+        if cancel.is_cancelled() {
+            break;
+        }
         let max = find_all_the_joltage(battery_bank, batteries_per_bank);
         max_joltage += max;
-    });
+    }
     Answer { max_joltage }
 }
 
@@ -104,14 +114,23 @@ impl Index<Range<usize>> for BatteryBank {
 struct BatteryBankParser {}
 
 impl BatteryBankParser {
-    fn parse(&self, reader: Reader) -> Vec<BatteryBank> {
+    fn parse(&self, mut reader: Reader) -> Result<Vec<BatteryBank>, ParseError> {
         reader
-            .map(|line| {
+            .numbered()
+            .map(|(row, line)| {
                 let batteries = line
-                    .chars()
-                    .map(|char| char.to_digit(10).unwrap() as u8)
-                    .collect::<Vec<u8>>();
-                BatteryBank { batteries }
+                    .char_indices()
+                    .map(|(i, char)| match char.to_digit(10) {
+                        Some(digit) => Ok(digit as u8),
+                        None => Err(ParseError::at(
+                            row,
+                            &line,
+                            i,
+                            format!("expected a digit, found `{char}`"),
+                        )),
+                    })
+                    .collect::<Result<Vec<u8>, ParseError>>()?;
+                Ok(BatteryBank { batteries })
             })
             .collect()
     }
@@ -119,26 +138,23 @@ impl BatteryBankParser {
 
 #[cfg(test)]
 mod tests {
-    use crate::challenges::day_03::{
+    use crate::challenges::year_2025::day_03::{
         find_all_the_joltage, sum_joltage, BatteryBank, BatteryBankParser, State, DAY,
     };
-    use crate::helpers::{Reader, PREFIX};
+    use crate::challenges::year_2025::sample;
+    use crate::helpers::{Cancellation, Reader};
 
     #[test]
     fn test_sample_input_easy() {
-        let input =
-            BatteryBankParser {}.parse(Reader::from_file(format!("{PREFIX}_{DAY}/sample.txt").as_str()));
-        let state = State { input };
-        let result = sum_joltage(&state, 2);
+        let state = sample(DAY).parse::<State>().unwrap();
+        let result = sum_joltage(&state, 2, &Cancellation::new());
         assert_eq!(result.max_joltage, 357);
     }
 
     #[test]
     fn test_sample_input_hard() {
-        let input =
-            BatteryBankParser {}.parse(Reader::from_file(format!("{PREFIX}_{DAY}/sample.txt").as_str()));
-        let state = State { input };
-        let result = sum_joltage(&state, 12);
+        let state = sample(DAY).parse::<State>().unwrap();
+        let result = sum_joltage(&state, 12, &Cancellation::new());
         assert_eq!(result.max_joltage, 3121910778619);
     }
 
@@ -146,6 +162,7 @@ mod tests {
     fn test_parser() {
         let result = BatteryBankParser {}
             .parse(Reader::single("12345"))
+            .unwrap()
             .pop()
             .unwrap();
         for i in 0..5 {
@@ -153,6 +170,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_parser_errors() {
+        let error = BatteryBankParser {}
+            .parse(Reader::from_vec(vec!["12345", "98 65"]))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 3));
+        assert_eq!(error.message, "expected a digit, found ` `");
+    }
+
     #[test]
     fn test_find_all_the_joltage() {
         let battery_bank = BatteryBank {
@@ -164,21 +191,15 @@ mod tests {
 
     #[test]
     fn test_easy_1() {
-        let input = vec![BatteryBank {
-            batteries: vec![1, 3, 5, 4, 2],
-        }];
-        let state = State { input };
-        let result = sum_joltage(&state, 2);
+        let state = "13542".parse::<State>().unwrap();
+        let result = sum_joltage(&state, 2, &Cancellation::new());
         assert_eq!(result.max_joltage, 54);
     }
 
     #[test]
     fn test_hard_1() {
-        let input = vec![BatteryBank {
-            batteries: vec![2, 1, 3, 5, 4, 2, 2, 3, 4, 5, 6, 7, 8],
-        }];
-        let state = State { input };
-        let result = sum_joltage(&state, 12);
+        let state = "2135422345678".parse::<State>().unwrap();
+        let result = sum_joltage(&state, 12, &Cancellation::new());
         assert_eq!(result.max_joltage, 235422345678);
     }
 }
@@ -0,0 +1,296 @@
+use crate::challenges::{Challenge, Registration, Solution};
+use crate::helpers::{Cancellation, Reader};
+use crate::parse::ParseError;
+use std::ops::{Index, IndexMut};
+use std::str::FromStr;
+use strum_macros::Display;
+
+const NAME: &str = "Printing Department";
+const DAY: &str = "04";
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2025,
+    day: 4,
+    title: NAME,
+    constructor: |text| Ok(Box::new(text.parse::<State>()?)),
+};
+
+pub struct State {
+    input: FactoryFloor,
+}
+
+impl FromStr for State {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let input = FactoryFloorParser {}.parse(Reader::from_text(text))?;
+        Ok(State { input })
+    }
+}
+
+impl Challenge for State {
+    fn preamble(&self) -> String {
+        format!("Day {DAY} - {NAME}")
+    }
+
+    fn run_easy(&mut self, cancel: &Cancellation) -> Solution {
+        let Answer { available_rolls } = do_easy(self, cancel);
+        Solution::labelled("Available", available_rolls)
+    }
+
+    fn run_hard(&mut self, cancel: &Cancellation) -> Solution {
+        let Answer { available_rolls } = do_hard(self, cancel);
+        Solution::labelled("Available", available_rolls)
+    }
+}
+
+fn do_easy(state: &State, cancel: &Cancellation) -> Answer {
+    let mut available_rolls = 0;
+    for r in 0..state.input.rows {
+        if cancel.is_cancelled() {
+            break;
+        }
+        for c in 0..state.input.cols {
+            if state.input[(r, c)] == Contents::Roll && is_available((r, c), &state.input) {
+                available_rolls += 1;
+            }
+        }
+    }
+    Answer { available_rolls }
+}
+
+fn do_hard(state: &State, cancel: &Cancellation) -> Answer {
+    let input = &mut state.input.clone();
+    let mut available_rolls = 0_u64;
+    while !cancel.is_cancelled() {
+        let mut changed = false;
+        for r in 0..input.rows {
+            for c in 0..input.cols {
+                if input[(r, c)] == Contents::Roll && is_available((r, c), input) {
+                    available_rolls += 1;
+                    input[(r, c)] = Contents::Marked;
+                    changed = true;
+                }
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+    Answer { available_rolls }
+}
+
+fn is_available((r, c): (usize, usize), factory_floor: &FactoryFloor) -> bool {
+    let (r, c) = (r as i32, c as i32);
+    let steps = [
+        (-1, -1),
+        (-1, 0),
+        (-1, 1),
+        (0, -1),
+        (0, 1),
+        (1, -1),
+        (1, 0),
+        (1, 1),
+    ];
+    let mut filled = 0;
+    for (x, y) in steps {
+        match factory_floor.safe_get(((r + x) as usize, (c + y) as usize)) {
+            Contents::Empty => {}
+            Contents::Roll => filled += 1,
+            Contents::Marked => {}
+        }
+    }
+    filled < 4
+}
+
+struct Answer {
+    available_rolls: u64,
+}
+
+#[derive(Display, Clone, Debug, PartialEq)]
+enum Contents {
+    #[strum(serialize = ".")]
+    Empty,
+    #[strum(serialize = "@")]
+    Roll,
+    #[strum(serialize = "X")]
+    Marked,
+}
+
+#[derive(Clone)]
+struct FactoryFloor {
+    cols: usize,
+    rows: usize,
+    floor: Vec<Contents>,
+}
+
+impl FactoryFloor {
+    fn idx(&self, (r, c): (usize, usize)) -> Option<usize> {
+        if r < self.rows && c < self.cols {
+            Some(r * self.cols + c)
+        } else {
+            None
+        }
+    }
+
+    fn safe_get(&self, (r, c): (usize, usize)) -> &Contents {
+        self.idx((r, c))
+            .map(|x| &self.floor[x])
+            .unwrap_or(&Contents::Empty)
+    }
+}
+
+impl Index<(usize, usize)> for FactoryFloor {
+    type Output = Contents;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        self.idx(index).map(|x| &self.floor[x]).unwrap()
+    }
+}
+
+impl IndexMut<(usize, usize)> for FactoryFloor {
+    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
+        self.idx(index).map(|x| &mut self.floor[x]).unwrap()
+    }
+}
+
+struct FactoryFloorParser {}
+
+impl FactoryFloorParser {
+    fn parse(&self, mut reader: Reader) -> Result<FactoryFloor, ParseError> {
+        let mut cols = None;
+        let mut rows = 0;
+        let floor = reader
+            .numbered()
+            .map(|(row, line)| {
+                let len = line.chars().count();
+                let width = *cols.get_or_insert(len);
+                if len != width {
+                    let offset = line
+                        .char_indices()
+                        .nth(width)
+                        .map_or(line.len(), |(i, _)| i);
+                    return Err(ParseError::at(
+                        row,
+                        &line,
+                        offset,
+                        format!("expected {width} columns, found {len}"),
+                    ));
+                }
+                rows += 1;
+                line.char_indices()
+                    .map(|(i, char)| match char {
+                        '.' => Ok(Contents::Empty),
+                        '@' => Ok(Contents::Roll),
+                        'X' => Ok(Contents::Marked),
+                        _ => Err(ParseError::at(
+                            row,
+                            &line,
+                            i,
+                            format!("expected `.`, `@` or `X`, found `{char}`"),
+                        )),
+                    })
+                    .collect::<Result<Vec<Contents>, ParseError>>()
+            })
+            .collect::<Result<Vec<Vec<Contents>>, ParseError>>()?
+            .into_iter()
+            .flatten()
+            .collect();
+        Ok(FactoryFloor {
+            cols: cols.unwrap_or(0),
+            rows,
+            floor,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::challenges::year_2025::day_04::{
+        do_easy, do_hard, is_available, Contents, FactoryFloorParser, State, DAY,
+    };
+    use crate::challenges::year_2025::sample;
+    use crate::helpers::{Cancellation, Reader};
+
+    #[test]
+    fn test_sample_input_easy() {
+        let state = sample(DAY).parse::<State>().unwrap();
+        let result = do_easy(&state, &Cancellation::new());
+        assert_eq!(result.available_rolls, 13);
+    }
+
+    #[test]
+    fn test_sample_input_hard() {
+        let state = sample(DAY).parse::<State>().unwrap();
+        let result = do_hard(&state, &Cancellation::new());
+        assert_eq!(result.available_rolls, 43);
+    }
+
+    #[test]
+    fn test_cancelled() {
+        let state = sample(DAY).parse::<State>().unwrap();
+        let cancel = Cancellation::new();
+        cancel.cancel();
+        assert_eq!(do_hard(&state, &cancel).available_rolls, 0);
+    }
+
+    #[test]
+    fn test_parser() {
+        let parser = FactoryFloorParser {};
+        let result = parser
+            .parse(Reader::from_vec(vec!["..@@.@@@@.", "@@..@....@"]))
+            .unwrap();
+        assert_eq!(result.rows, 2);
+        assert_eq!(result.cols, 10);
+        assert_eq!(result.safe_get((1, 3)).clone(), Contents::Empty);
+        assert_eq!(result.safe_get((0, 3)).clone(), Contents::Roll);
+    }
+
+    #[test]
+    fn test_parser_errors() {
+        let error = FactoryFloorParser {}
+            .parse(Reader::from_vec(vec!["..@@", "@#.."]))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.message, "expected `.`, `@` or `X`, found `#`");
+        let error = FactoryFloorParser {}
+            .parse(Reader::from_vec(vec!["..@@", "@.@.", "@.", "@...."]))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(error.message, "expected 4 columns, found 2");
+        let error = FactoryFloorParser {}
+            .parse(Reader::from_vec(vec!["..@@", "@.@.@"]))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 5));
+        let error = FactoryFloorParser {}
+            .parse(Reader::from_vec(vec!["..@", "ééé."]))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.message, "expected 3 columns, found 4");
+    }
+
+    #[test]
+    fn test_is_available() {
+        let parser = FactoryFloorParser {};
+        let input = parser
+            .parse(Reader::from_vec(vec![
+                "..........",
+                "...@......",
+                "..@.......",
+                ".@.@..@.@.",
+                ".......@..",
+                "......@.@.",
+            ]))
+            .unwrap();
+        assert_eq!(input.safe_get((2, 2)).clone(), Contents::Roll);
+        assert_eq!(input.safe_get((4, 7)).clone(), Contents::Roll);
+        let res = is_available((2, 2), &input);
+        assert_eq!(res, true);
+        let res = is_available((4, 7), &input);
+        assert_eq!(res, false);
+    }
+}
@@ -1,29 +1,36 @@
-use crate::challenges::Challenge;
-use crate::helpers::{Reader, PREFIX};
+use crate::challenges::{Challenge, Registration, Solution};
+use crate::helpers::{Cancellation, Reader};
+use crate::parse::{number, ParseError};
 use std::cmp::{max, min};
+use std::str::FromStr;
 
 const NAME: &str = "Cafeteria";
 const DAY: &str = "05";
 
+pub const REGISTRATION: Registration = Registration {
+    year: 2025,
+    day: 5,
+    title: NAME,
+    constructor: |text| Ok(Box::new(text.parse::<State>()?)),
+};
+
 pub struct State {
     ranges: Vec<Range>,
     items: Vec<u64>,
 }
 
-impl State {
-    pub fn new() -> Self
-    where
-        Self: Sized,
-    {
-        let mut reader = Reader::from_file(format!("{PREFIX}_{DAY}/input.txt").as_str());
+impl FromStr for State {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
         let range_parser = RangeParser {};
         let item_parser = ItemParser {};
-        let Database {ranges, items} = CombiParser {
+        let Database { ranges, items } = CombiParser {
             range_parser,
             item_parser,
         }
-            .parse(&mut reader);
-        State { ranges, items }
+        .parse(&mut Reader::from_text(text))?;
+        Ok(State { ranges, items })
     }
 }
 
@@ -32,20 +39,26 @@ impl Challenge for State {
         format!("Day {DAY} - {NAME}")
     }
 
-    fn run_easy(&mut self) -> String {
-        let Answer { unspoiled_food, .. } = do_easy(&self);
-        format!("Unspoiled: {unspoiled_food}")
+    fn run_easy(&mut self, cancel: &Cancellation) -> Solution {
+        let Answer { unspoiled_food, .. } = do_easy(self, cancel);
+        Solution::labelled("Unspoiled", unspoiled_food)
     }
 
-    fn run_hard(&mut self) -> String {
-        let Answer { total_unspoiled_foods, .. } = do_hard(&self);
-        format!("Total Unspoiled: {total_unspoiled_foods}")
+    fn run_hard(&mut self, cancel: &Cancellation) -> Solution {
+        let Answer {
+            total_unspoiled_foods,
+            ..
+        } = do_hard(self, cancel);
+        Solution::labelled("Total Unspoiled", total_unspoiled_foods)
     }
 }
 
-fn do_easy(state: &State) -> Answer {
+fn do_easy(state: &State, cancel: &Cancellation) -> Answer {
     let mut unspoiled_food = 0;
     for item in &state.items {
+        if cancel.is_cancelled() {
+            break;
+        }
         for range in &state.ranges {
             if range.start <= *item && range.end >= *item {
                 unspoiled_food += 1;
@@ -59,9 +72,12 @@ fn do_easy(state: &State) -> Answer {
     }
 }
 
-fn do_hard(state: &State) -> Answer {
+fn do_hard(state: &State, cancel: &Cancellation) -> Answer {
     let mut total_unspoiled_foods = 0;
     for range in &state.ranges {
+        if cancel.is_cancelled() {
+            break;
+        }
         total_unspoiled_foods += range.end - range.start + 1;
     }
     Answer {
@@ -152,79 +168,70 @@ struct CombiParser {
 }
 
 impl RangeParser {
-    fn parse(&self, reader: &mut Reader) -> Vec<Range> {
+    fn parse(&self, reader: &mut Reader) -> Result<Vec<Range>, ParseError> {
         let mut ranges = reader
-            .take_while(|line| line != "")
-            .map(|line| {
-                let (start, end) = line.split_once("-").unwrap();
-                let start = start.parse::<u64>().unwrap();
-                let end = end.parse::<u64>().unwrap();
-                Range { start, end }
+            .numbered()
+            .take_while(|(_, line)| !line.is_empty())
+            .map(|(row, line)| {
+                let Some((start, end)) = line.split_once("-") else {
+                    return Err(ParseError::at(row, &line, line.len(), "expected `-`"));
+                };
+                let start = number::<u64>(row, &line, start)?;
+                let end = number::<u64>(row, &line, end)?;
+                Ok(Range { start, end })
             })
-            .collect::<Vec<Range>>();
-        ranges.sort_by(|a, b| a.start.cmp(&b.start));
-        ranges
+            .collect::<Result<Vec<Range>, ParseError>>()?;
+        ranges.sort_by_key(|range| range.start);
+        Ok(ranges)
     }
 }
 
 impl ItemParser {
-    fn parse(&self, reader: &mut Reader) -> Vec<u64> {
+    fn parse(&self, reader: &mut Reader) -> Result<Vec<u64>, ParseError> {
         reader
-            .take_while(|line| line != "")
-            .map(|line| line.parse::<u64>().unwrap())
-            .collect::<Vec<u64>>()
+            .numbered()
+            .take_while(|(_, line)| !line.is_empty())
+            .map(|(row, line)| number::<u64>(row, &line, &line))
+            .collect::<Result<Vec<u64>, ParseError>>()
     }
 }
 
 impl CombiParser {
-    fn parse(&self, reader: &mut Reader) -> Database {
-        let ranges = self.range_parser.parse(reader);
-        let items = self.item_parser.parse(reader);
+    fn parse(&self, reader: &mut Reader) -> Result<Database, ParseError> {
+        let ranges = self.range_parser.parse(reader)?;
+        let items = self.item_parser.parse(reader)?;
         let ranges = merge_ranges(ranges);
-        Database { ranges, items }
+        Ok(Database { ranges, items })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::challenges::day_05::{do_easy, do_hard, CombiParser, ItemParser, Range, RangeParser, State, DAY};
-    use crate::helpers::{Reader, PREFIX};
+    use crate::challenges::year_2025::day_05::{
+        do_easy, do_hard, CombiParser, ItemParser, Range, RangeParser, State, DAY,
+    };
+    use crate::challenges::year_2025::sample;
+    use crate::helpers::{Cancellation, Reader};
 
     #[test]
     fn test_sample_input_easy() {
-        let range_parser = RangeParser {};
-        let item_parser = ItemParser {};
-        let input = CombiParser {
-            range_parser,
-            item_parser,
-        }
-        .parse(&mut Reader::from_file(
-            format!("{PREFIX}_{DAY}/sample.txt").as_str(),
-        ));
-        let state = State { ranges: input.ranges, items: input.items };
-        let result = do_easy(&state);
+        let state = sample(DAY).parse::<State>().unwrap();
+        let result = do_easy(&state, &Cancellation::new());
         assert_eq!(result.unspoiled_food, 3);
     }
 
     #[test]
     fn test_sample_input_hard() {
-        let range_parser = RangeParser {};
-        let item_parser = ItemParser {};
-        let input = CombiParser {
-            range_parser,
-            item_parser,
-        }
-        .parse(&mut Reader::from_file(
-            format!("{PREFIX}_{DAY}/sample.txt").as_str(),
-        ));
-        let state = State { ranges: input.ranges, items: input.items };
-        let result = do_hard(&state);
+        let state = sample(DAY).parse::<State>().unwrap();
+        let result = do_hard(&state, &Cancellation::new());
         assert_eq!(result.total_unspoiled_foods, 14);
     }
 
     #[test]
     fn test_range_parser() {
-        let result = RangeParser {}.parse(&mut Reader::from_vec(vec!["11-15", "35-41"]));
+        let result = RangeParser {}
+            .parse(&mut Reader::from_vec(vec!["11-15", "35-41"]))
+            .unwrap();
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].start, 11);
         assert_eq!(result[0].end, 15);
@@ -232,7 +239,9 @@ mod tests {
 
     #[test]
     fn test_item_parser() {
-        let result = ItemParser {}.parse(&mut Reader::from_vec(vec!["1", "3"]));
+        let result = ItemParser {}
+            .parse(&mut Reader::from_vec(vec!["1", "3"]))
+            .unwrap();
         assert_eq!(result.len(), 2);
         assert_eq!(result[0], 1);
         assert_eq!(result[1], 3);
@@ -241,8 +250,8 @@ mod tests {
     #[test]
     fn test_combined_parser() {
         let mut reader = Reader::from_vec(vec!["11-15", "35-41", "", "1", "3"]);
-        let ranges = RangeParser {}.parse(&mut reader);
-        let items = ItemParser {}.parse(&mut reader);
+        let ranges = RangeParser {}.parse(&mut reader).unwrap();
+        let items = ItemParser {}.parse(&mut reader).unwrap();
         assert_eq!(ranges.len(), 2);
         assert_eq!(ranges[0].start, 11);
         assert_eq!(ranges[0].end, 15);
@@ -251,6 +260,21 @@ mod tests {
         assert_eq!(items[1], 3);
     }
 
+    #[test]
+    fn test_parser_errors() {
+        let parser = CombiParser {
+            range_parser: RangeParser {},
+            item_parser: ItemParser {},
+        };
+        let mut reader = Reader::from_vec(vec!["11-15", "", "1", "3x"]);
+        let error = parser.parse(&mut reader).err().unwrap();
+        assert_eq!((error.line, error.column), (4, 2));
+        let mut reader = Reader::from_vec(vec!["11-15", "35:41"]);
+        let error = parser.parse(&mut reader).err().unwrap();
+        assert_eq!((error.line, error.column), (2, 6));
+        assert_eq!(error.message, "expected `-`");
+    }
+
     #[test]
     #[should_panic]
     fn test_merge_does_not_merge_distinct_higher() {
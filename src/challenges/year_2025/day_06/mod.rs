@@ -0,0 +1,264 @@
+use crate::challenges::{Challenge, Registration, Solution};
+use crate::helpers::{Cancellation, Reader};
+use crate::parse::{number, ParseError};
+use std::str::FromStr;
+
+const NAME: &str = "Trash Compactor";
+const DAY: &str = "06";
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2025,
+    day: 6,
+    title: NAME,
+    constructor: |text| Ok(Box::new(text.parse::<State>()?)),
+};
+
+pub struct State {
+    input: PartialProblem,
+}
+
+impl FromStr for State {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let input = ProblemParser {}.parse(&mut Reader::from_text(text))?;
+        Ok(State { input })
+    }
+}
+
+impl Challenge for State {
+    fn preamble(&self) -> String {
+        format!("Day {DAY} - {NAME}")
+    }
+
+    fn run_easy(&mut self, cancel: &Cancellation) -> Solution {
+        let Answer { sum_of_problems } = do_easy(self, cancel);
+        Solution::labelled("Sum of Problems", sum_of_problems)
+    }
+
+    fn run_hard(&mut self, cancel: &Cancellation) -> Solution {
+        let Answer { sum_of_problems } = do_hard(self, cancel);
+        Solution::labelled("Sum of Problems", sum_of_problems)
+    }
+}
+
+fn do_easy(state: &State, cancel: &Cancellation) -> Answer {
+    let PartialProblem { rows, operands, .. } = &state.input;
+    let sum_of_problems = (0..operands.len())
+        .take_while(|_| !cancel.is_cancelled())
+        .map(|i| {
+            let numbers = rows.iter().map(|n| n[i]).collect::<Vec<u64>>();
+            let operand = operands[i].clone();
+            solve_problem(&Problem { numbers, operand })
+        })
+        .sum();
+    Answer { sum_of_problems }
+}
+
+fn do_hard(state: &State, cancel: &Cancellation) -> Answer {
+    let PartialProblem {
+        lines, operands, ..
+    } = &state.input;
+    let sum_of_problems = make_stupid_numbers(lines, operands)
+        .iter()
+        .take_while(|_| !cancel.is_cancelled())
+        .map(solve_problem)
+        .sum();
+    Answer { sum_of_problems }
+}
+
+fn make_stupid_numbers(lines: &Vec<String>, operands: &Vec<Operand>) -> Vec<Problem> {
+    let mut problems = Vec::with_capacity(operands.len());
+    let mut i = 0;
+    while problems.len() < operands.len() {
+        let mut numbers: Vec<u64> = Vec::with_capacity(10);
+        let operand = operands[problems.len()].clone();
+        loop {
+            match make_stupid_number(i, lines) {
+                Some(num) => numbers.push(num),
+                None => {
+                    problems.push(Problem { numbers, operand });
+                    i += 1;
+                    break;
+                }
+            }
+            i += 1;
+        }
+    }
+    problems
+}
+
+fn make_stupid_number(i: usize, lines: &Vec<String>) -> Option<u64> {
+    let mut result: u64 = 0;
+    let mut found = false;
+    for line in lines {
+        if let Some(ch) = line.as_bytes().get(i) {
+            if ch.is_ascii_digit() {
+                result = result * 10 + (ch - b'0') as u64;
+                found = true;
+            }
+        };
+    }
+    if found {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+fn solve_problem(problem: &Problem) -> u64 {
+    match problem.operand {
+        Operand::Add => problem.numbers.iter().sum(),
+        Operand::Mul => problem.numbers.iter().product(),
+    }
+}
+
+struct Answer {
+    sum_of_problems: u64,
+}
+
+#[derive(Clone, PartialEq, Debug)]
+enum Operand {
+    Add,
+    Mul,
+}
+
+#[derive(Clone)]
+struct Problem {
+    numbers: Vec<u64>,
+    operand: Operand,
+}
+
+#[derive(Clone)]
+struct PartialProblem {
+    lines: Vec<String>,
+    rows: Vec<Vec<u64>>,
+    operands: Vec<Operand>,
+}
+
+struct ProblemParser {}
+
+impl ProblemParser {
+    fn parse(&self, reader: &mut Reader) -> Result<PartialProblem, ParseError> {
+        let mut lines = reader.numbered().collect::<Vec<(usize, String)>>();
+        let Some((row, operators)) = lines.pop() else {
+            return Err(ParseError::at(1, "", 0, "expected a line of operators"));
+        };
+        let operands = operators
+            .split_whitespace()
+            .map(|x| match x {
+                "+" => Ok(Operand::Add),
+                "*" => Ok(Operand::Mul),
+                _ => Err(ParseError::in_field(
+                    row,
+                    &operators,
+                    x,
+                    format!("expected `+` or `*`, found `{x}`"),
+                )),
+            })
+            .collect::<Result<Vec<Operand>, ParseError>>()?;
+        let mut rows = Vec::with_capacity(lines.len());
+        for (row, line) in &lines {
+            if let Some((i, char)) = line
+                .char_indices()
+                .find(|(_, c)| !c.is_ascii_digit() && *c != ' ')
+            {
+                let message = format!("expected a digit or a space, found `{char}`");
+                return Err(ParseError::at(*row, line, i, message));
+            }
+            let fields = line.split_whitespace().collect::<Vec<&str>>();
+            if fields.len() != operands.len() {
+                let message = format!(
+                    "expected {} numbers to match the operators, found {}",
+                    operands.len(),
+                    fields.len()
+                );
+                return Err(match fields.get(operands.len()) {
+                    Some(extra) => ParseError::in_field(*row, line, extra, message),
+                    None => ParseError::at(*row, line, line.len(), message),
+                });
+            }
+            let numbers = fields
+                .iter()
+                .map(|field| number::<u64>(*row, line, field))
+                .collect::<Result<Vec<u64>, ParseError>>()?;
+            rows.push(numbers);
+        }
+        let lines = lines.into_iter().map(|(_, line)| line).collect();
+        Ok(PartialProblem {
+            lines,
+            rows,
+            operands,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::challenges::year_2025::day_06::{
+        do_easy, do_hard, Operand, ProblemParser, State, DAY,
+    };
+    use crate::challenges::year_2025::sample;
+    use crate::helpers::{Cancellation, Reader};
+
+    #[test]
+    fn test_sample_input_easy() {
+        let state = sample(DAY).parse::<State>().unwrap();
+        let result = do_easy(&state, &Cancellation::new());
+        assert_eq!(result.sum_of_problems, 4277556);
+    }
+
+    #[test]
+    fn test_sample_input_hard() {
+        let state = sample(DAY).parse::<State>().unwrap();
+        let result = do_hard(&state, &Cancellation::new());
+        assert_eq!(result.sum_of_problems, 3263827);
+    }
+
+    #[test]
+    fn test_problem_parser() {
+        let result = ProblemParser {}
+            .parse(&mut Reader::from_vec(vec![
+                "1 5", "2 6", "3 7", "4 8", "+ *",
+            ]))
+            .unwrap();
+        assert_eq!(result.operands.len(), 2);
+        assert_eq!(result.operands[0], Operand::Add);
+        assert_eq!(result.operands[1], Operand::Mul);
+        assert_eq!(result.lines.len(), 4);
+        assert_eq!(result.lines[0], "1 5");
+        assert_eq!(result.lines[1], "2 6");
+        assert_eq!(result.lines[2], "3 7");
+        assert_eq!(result.lines[3], "4 8");
+        assert_eq!(result.rows, vec![[1, 5], [2, 6], [3, 7], [4, 8]]);
+    }
+
+    #[test]
+    fn test_problem_parser_errors() {
+        let error = ProblemParser {}
+            .parse(&mut Reader::from_vec(vec!["1 5", "2 6", "+ -"]))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (3, 3));
+        assert_eq!(error.message, "expected `+` or `*`, found `-`");
+        let error = ProblemParser {}
+            .parse(&mut Reader::from_vec(vec!["1 5", "2,6", "+ *"]))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 2));
+        let error = ProblemParser {}
+            .parse(&mut Reader::from_vec(vec!["1 2", "3", "+ *"]))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(
+            error.message,
+            "expected 2 numbers to match the operators, found 1"
+        );
+        let error = ProblemParser {}
+            .parse(&mut Reader::from_vec(vec!["1 2  3", "+ *"]))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (1, 6));
+    }
+}
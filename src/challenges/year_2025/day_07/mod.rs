@@ -1,24 +1,31 @@
-use crate::challenges::Challenge;
-use crate::helpers::{Reader, PREFIX};
+use crate::challenges::{Challenge, Registration, Solution};
+use crate::helpers::{Cancellation, Reader};
+use crate::parse::ParseError;
 use std::collections::{HashMap, HashSet};
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 use strum_macros::Display;
 
 const NAME: &str = "Laboratories";
 const DAY: &str = "07";
 
+pub const REGISTRATION: Registration = Registration {
+    year: 2025,
+    day: 7,
+    title: NAME,
+    constructor: |text| Ok(Box::new(text.parse::<State>()?)),
+};
+
 pub struct State {
     input: Grid,
 }
 
-impl State {
-    pub fn new() -> Self
-    where
-        Self: Sized,
-    {
-        let mut reader = Reader::from_file(format!("{PREFIX}_{DAY}/input.txt").as_str());
-        let input = GridParser {}.parse(&mut reader);
-        State { input }
+impl FromStr for State {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let input = GridParser {}.parse(&mut Reader::from_text(text))?;
+        Ok(State { input })
     }
 }
 
@@ -27,18 +34,20 @@ impl Challenge for State {
         format!("Day {DAY} - {NAME}")
     }
 
-    fn run_easy(&mut self) -> String {
-        let Answer { tachyon_splits, .. } = run_manifold(self);
-        format!("Tachyon Splits: {tachyon_splits}")
+    fn run_easy(&mut self, cancel: &Cancellation) -> Solution {
+        let Answer { tachyon_splits, .. } = run_manifold(self, cancel);
+        Solution::labelled("Tachyon Splits", tachyon_splits)
     }
 
-    fn run_hard(&mut self) -> String {
-        let Answer { tachyon_timelines, .. } = run_manifold(self);
-        format!("Tachyon Timelines: {tachyon_timelines}")
+    fn run_hard(&mut self, cancel: &Cancellation) -> Solution {
+        let Answer {
+            tachyon_timelines, ..
+        } = run_manifold(self, cancel);
+        Solution::labelled("Tachyon Timelines", tachyon_timelines)
     }
 }
 
-fn run_manifold(state: &mut State) -> Answer {
+fn run_manifold(state: &mut State, cancel: &Cancellation) -> Answer {
     let mut input = state.input.clone();
     let start = input
         .get_row(0)
@@ -55,7 +64,7 @@ fn run_manifold(state: &mut State) -> Answer {
     let cols = input.cols;
     let rows = input.rows;
     let mut next_row = 1_usize;
-    while next_row < rows {
+    while next_row < rows && !cancel.is_cancelled() {
         let mut next_tachyons: HashSet<usize> = HashSet::new();
         let row: &mut [Contents] = input.get_row_mut(next_row);
         for col in tachyons.iter() {
@@ -190,59 +199,94 @@ impl Display for Grid {
 struct GridParser {}
 
 impl GridParser {
-    fn parse(&self, reader: &mut Reader) -> Grid {
+    fn parse(&self, reader: &mut Reader) -> Result<Grid, ParseError> {
         let mut rows = 0;
-        let mut cols = 0;
+        let mut cols = None;
         let manifold = reader
-            .collect::<Vec<String>>()
-            .iter()
-            .flat_map(|row| {
-                cols = row.len();
+            .numbered()
+            .map(|(number, row)| {
+                let len = row.chars().count();
+                let width = *cols.get_or_insert(len);
+                if len != width {
+                    let offset = row.char_indices().nth(width).map_or(row.len(), |(i, _)| i);
+                    return Err(ParseError::at(
+                        number,
+                        &row,
+                        offset,
+                        format!("expected {width} columns, found {len}"),
+                    ));
+                }
+                let contents = row
+                    .char_indices()
+                    .map(|(i, x)| match x {
+                        '.' => Ok(Contents::Empty),
+                        '^' => Ok(Contents::Splitter),
+                        'S' => Ok(Contents::Emitter),
+                        _ => Err(ParseError::at(
+                            number,
+                            &row,
+                            i,
+                            format!("expected `.`, `^` or `S`, found `{x}`"),
+                        )),
+                    })
+                    .collect::<Result<Vec<Contents>, ParseError>>()?;
+                if rows == 0 {
+                    let emitters = row.match_indices('S').map(|(i, _)| i).collect::<Vec<_>>();
+                    if emitters.len() != 1 {
+                        return Err(ParseError::at(
+                            number,
+                            &row,
+                            emitters.get(1).copied().unwrap_or(0),
+                            format!(
+                                "expected one `S` in the first row, found {}",
+                                emitters.len()
+                            ),
+                        ));
+                    }
+                }
                 rows += 1;
-                row.chars().into_iter().map(|x| match x {
-                    '.' => Contents::Empty,
-                    '^' => Contents::Splitter,
-                    'S' => Contents::Emitter,
-                    _ => panic!("Unexpected case"),
-                })
+                Ok(contents)
             })
+            .collect::<Result<Vec<Vec<Contents>>, ParseError>>()?
+            .into_iter()
+            .flatten()
             .collect::<Vec<Contents>>();
-        Grid {
+        if rows == 0 {
+            return Err(ParseError::at(1, "", 0, "expected a row with an `S`"));
+        }
+        Ok(Grid {
             rows,
-            cols,
+            cols: cols.unwrap_or(0),
             manifold,
-        }
+        })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::challenges::day_07::{run_manifold, Contents, GridParser, State, DAY};
-    use crate::helpers::{Reader, PREFIX};
+    use crate::challenges::year_2025::day_07::{run_manifold, Contents, GridParser, State, DAY};
+    use crate::challenges::year_2025::sample;
+    use crate::helpers::{Cancellation, Reader};
 
     #[test]
     fn test_sample_input_easy() {
-        let input = GridParser {}.parse(&mut Reader::from_file(
-            format!("{PREFIX}_{DAY}/sample.txt").as_str(),
-        ));
-        let mut state = State { input };
-        let result = run_manifold(&mut state);
+        let mut state = sample(DAY).parse::<State>().unwrap();
+        let result = run_manifold(&mut state, &Cancellation::new());
         assert_eq!(result.tachyon_splits, 21);
     }
 
     #[test]
     fn test_sample_input_hard() {
-        let input = GridParser {}.parse(&mut Reader::from_file(
-            format!("{PREFIX}_{DAY}/sample.txt").as_str(),
-        ));
-        let mut state = State { input };
-        let result = run_manifold(&mut state);
+        let mut state = sample(DAY).parse::<State>().unwrap();
+        let result = run_manifold(&mut state, &Cancellation::new());
         assert_eq!(result.tachyon_timelines, 40);
     }
 
     #[test]
     fn test_problem_parser() {
-        let result = GridParser {}.parse(&mut Reader::from_vec(vec!["..S..", ".^.^.", "^...^"]));
+        let result = GridParser {}
+            .parse(&mut Reader::from_vec(vec!["..S..", ".^.^.", "^...^"]))
+            .unwrap();
         assert_eq!(result.rows, 3);
         assert_eq!(result.cols, 5);
         assert_eq!(*result.get((0, 2)), Contents::Emitter);
@@ -261,4 +305,42 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn test_problem_parser_errors() {
+        let error = GridParser {}
+            .parse(&mut Reader::from_vec(vec!["..S..", ".^.v."]))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.message, "expected `.`, `^` or `S`, found `v`");
+        let error = GridParser {}
+            .parse(&mut Reader::from_vec(vec!["..S..", ".^.", "^...^"]))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.message, "expected 5 columns, found 3");
+        let error = GridParser {}
+            .parse(&mut Reader::from_vec(vec!["..S", "ééé."]))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.message, "expected 3 columns, found 4");
+        let error = GridParser {}
+            .parse(&mut Reader::from_vec(vec![".....", ".^.^."]))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (1, 1));
+        assert_eq!(error.message, "expected one `S` in the first row, found 0");
+        let error = GridParser {}
+            .parse(&mut Reader::from_vec(vec![".S.S.", ".^.^."]))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (1, 4));
+        let error = GridParser {}
+            .parse(&mut Reader::from_vec(vec![]))
+            .err()
+            .unwrap();
+        assert_eq!(error.message, "expected a row with an `S`");
+    }
 }
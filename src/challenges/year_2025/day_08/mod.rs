@@ -1,23 +1,30 @@
-use crate::challenges::Challenge;
-use crate::helpers::{Reader, PREFIX};
+use crate::challenges::{Challenge, Registration, Solution};
+use crate::helpers::{Cancellation, Reader};
+use crate::parse::{number, ParseError};
 use std::cmp::Reverse;
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
 
 const NAME: &str = "Playground";
 const DAY: &str = "08";
 
+pub const REGISTRATION: Registration = Registration {
+    year: 2025,
+    day: 8,
+    title: NAME,
+    constructor: |text| Ok(Box::new(text.parse::<State>()?)),
+};
+
 pub struct State {
     input: Input,
 }
 
-impl State {
-    pub fn new() -> Self
-    where
-        Self: Sized,
-    {
-        let mut reader = Reader::from_file(format!("{PREFIX}_{DAY}/input.txt").as_str());
-        let input = CoordinateParser {}.parse(&mut reader);
-        State { input }
+impl FromStr for State {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let input = CoordinateParser {}.parse(&mut Reader::from_text(text))?;
+        Ok(State { input })
     }
 }
 
@@ -26,15 +33,15 @@ impl Challenge for State {
         format!("Day {DAY} - {NAME}")
     }
 
-    fn run_easy(&mut self) -> String {
-        let result = connect_circuits(&self, 1000, 3);
-        format!("Largest Circuits space: {}", result.largest_circuits_space)
+    fn run_easy(&mut self, cancel: &Cancellation) -> Solution {
+        let result = connect_circuits(&self, 1000, 3, cancel);
+        Solution::labelled("Largest Circuits space", result.largest_circuits_space)
     }
 
-    fn run_hard(&mut self) -> String {
+    fn run_hard(&mut self, cancel: &Cancellation) -> Solution {
         let max = self.input.junctions.len() * (self.input.junctions.len() - 1) / 2;
-        let result = connect_circuits(&self, max, 1);
-        format!("Last span: {}", result.last_span)
+        let result = connect_circuits(&self, max, 1, cancel);
+        Solution::labelled("Last span", result.last_span)
     }
 }
 
@@ -42,6 +49,7 @@ fn connect_circuits(
     state: &State,
     mut max_junctions_to_connect: usize,
     circuits_to_count: usize,
+    cancel: &Cancellation,
 ) -> Answer {
     let junctions = &state.input.junctions;
     let mut circuits: Vec<Circuit> = Vec::new();
@@ -49,7 +57,12 @@ fn connect_circuits(
     let mut it = items.iter();
     let mut last_span = 0u64;
     while max_junctions_to_connect > 0 {
-        let item = it.next().unwrap();
+        if cancel.is_cancelled() {
+            break;
+        }
+        let Some(item) = it.next() else {
+            break;
+        };
         let first_idx = get_circuit_idx(&item.first, &circuits);
         let second_idx = get_circuit_idx(&item.second, &circuits);
         last_span = item.first.x * item.second.x;
@@ -164,48 +177,55 @@ impl Junction {
 struct CoordinateParser {}
 
 impl CoordinateParser {
-    fn parse(&self, reader: &mut Reader) -> Input {
+    fn parse(&self, reader: &mut Reader) -> Result<Input, ParseError> {
+        let mut seen = HashMap::new();
         let junctions = reader
-            .map(|line| {
-                let [x, y, z] = line
+            .numbered()
+            .map(|(row, line)| {
+                let coords = line
                     .split(",")
-                    .map(|x| x.parse::<u64>().unwrap())
-                    .collect::<Vec<u64>>()[..]
-                else {
-                    panic!("Line did not split into 3 coords")
+                    .map(|x| number::<u64>(row, &line, x))
+                    .collect::<Result<Vec<u64>, ParseError>>()?;
+                let [x, y, z] = coords[..] else {
+                    let message = format!("expected 3 coordinates, found {}", coords.len());
+                    return Err(ParseError::at(row, &line, 0, message));
                 };
-                Junction { x, y, z }
+                if let Some(first) = seen.insert((x, y, z), row) {
+                    let message =
+                        format!("junction {x},{y},{z} was already listed on line {first}");
+                    return Err(ParseError::at(row, &line, 0, message));
+                }
+                Ok(Junction { x, y, z })
             })
-            .collect();
-        Input { junctions }
+            .collect::<Result<Vec<Junction>, ParseError>>()?;
+        if junctions.len() < 2 {
+            let message = format!("expected at least 2 junctions, found {}", junctions.len());
+            return Err(ParseError::at(junctions.len() + 1, "", 0, message));
+        }
+        Ok(Input { junctions })
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::challenges::day_08::{
+    use crate::challenges::year_2025::day_08::{
         compute_distances, connect_circuits, CoordinateParser, Junction, JunctionPair, State, DAY,
     };
-    use crate::helpers::{Reader, PREFIX};
+    use crate::challenges::year_2025::sample;
+    use crate::helpers::{Cancellation, Reader};
 
     #[test]
     fn test_sample_input_easy() {
-        let input = CoordinateParser {}.parse(&mut Reader::from_file(
-            format!("{PREFIX}_{DAY}/sample.txt").as_str(),
-        ));
-        let state = State { input };
-        let result = connect_circuits(&state, 10, 3);
+        let state = sample(DAY).parse::<State>().unwrap();
+        let result = connect_circuits(&state, 10, 3, &Cancellation::new());
         assert_eq!(result.largest_circuits_space, 40);
         assert_eq!(result.last_span, 891504);
     }
 
     #[test]
     fn test_sample_input_hard() {
-        let input = CoordinateParser {}.parse(&mut Reader::from_file(
-            format!("{PREFIX}_{DAY}/sample.txt").as_str(),
-        ));
-        let state = State { input };
-        let result = connect_circuits(&state, 1000, 3);
+        let state = sample(DAY).parse::<State>().unwrap();
+        let result = connect_circuits(&state, 1000, 3, &Cancellation::new());
         assert_eq!(result.largest_circuits_space, state.input.junctions.len());
         assert_eq!(result.last_span, 25272);
     }
@@ -247,8 +267,9 @@ mod tests {
 
     #[test]
     fn test_problem_parser() {
-        let result =
-            CoordinateParser {}.parse(&mut Reader::from_vec(vec!["162,817,812", "57,618,57"]));
+        let result = CoordinateParser {}
+            .parse(&mut Reader::from_vec(vec!["162,817,812", "57,618,57"]))
+            .unwrap();
         assert_eq!(
             result.junctions[0],
             Junction {
@@ -266,4 +287,30 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_problem_parser_errors() {
+        let parser = CoordinateParser {};
+        let error = parser
+            .parse(&mut Reader::from_vec(vec!["162,817,812", "57,618"]))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        assert_eq!(error.message, "expected 3 coordinates, found 2");
+        let error = parser
+            .parse(&mut Reader::single("162,-817,812"))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (1, 5));
+        let error = parser.parse(&mut Reader::from_vec(vec![])).err().unwrap();
+        assert_eq!(error.message, "expected at least 2 junctions, found 0");
+        let error = parser.parse(&mut Reader::single("1,2,3")).err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+        let error = parser
+            .parse(&mut Reader::from_vec(vec!["1,2,3", "4,5,6", "1,2,3"]))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (3, 1));
+        assert_eq!(error.message, "junction 1,2,3 was already listed on line 1");
+    }
 }
@@ -0,0 +1,81 @@
+use crate::challenges::{Challenge, Registration, Solution};
+use crate::helpers::{Cancellation, Reader};
+use crate::parse::ParseError;
+use std::str::FromStr;
+
+const NAME: &str = "Reactor";
+const DAY: &str = "11";
+
+pub const REGISTRATION: Registration = Registration {
+    year: 2025,
+    day: 11,
+    title: NAME,
+    constructor: |text| Ok(Box::new(text.parse::<State>()?)),
+};
+
+pub struct State {
+    input: Input,
+}
+
+impl FromStr for State {
+    type Err = ParseError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        let input = Parser {}.parse(&mut Reader::from_text(text))?;
+        Ok(State { input })
+    }
+}
+
+impl Challenge for State {
+    fn preamble(&self) -> String {
+        format!("Day {DAY} - {NAME}")
+    }
+
+    fn run_easy(&mut self, _cancel: &Cancellation) -> Solution {
+        Solution::Unsolved
+    }
+
+    fn run_hard(&mut self, _cancel: &Cancellation) -> Solution {
+        Solution::Unsolved
+    }
+}
+
+struct Answer {}
+
+struct Input {}
+
+struct Parser {}
+
+impl Parser {
+    fn parse(&self, _reader: &mut Reader) -> Result<Input, ParseError> {
+        Ok(Input {})
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::challenges::year_2025::day_11::{Parser, State, DAY};
+    use crate::challenges::year_2025::sample;
+    use crate::challenges::{Challenge, Solution};
+    use crate::helpers::{Cancellation, Reader};
+
+    #[test]
+    fn test_sample_input_easy() {
+        let mut state = sample(DAY).parse::<State>().unwrap();
+        let result = state.run_easy(&Cancellation::new());
+        assert_eq!(result, Solution::Unsolved);
+    }
+
+    #[test]
+    fn test_sample_input_hard() {
+        let mut state = sample(DAY).parse::<State>().unwrap();
+        let result = state.run_hard(&Cancellation::new());
+        assert_eq!(result, Solution::Unsolved);
+    }
+
+    #[test]
+    fn test_problem_parser() {
+        let result = Parser {}.parse(&mut Reader::from_vec(vec!["..S..", ".^.^.", "^...^"]));
+        assert!(result.is_ok());
+    }
+}
@@ -0,0 +1,24 @@
+use crate::challenges::Registration;
+
+#[cfg(test)]
+pub const PREFIX: &str = crate::helpers::root_path!("/src/challenges/year_2025/day");
+
+#[cfg(test)]
+pub fn sample(day: &str) -> String {
+    std::fs::read_to_string(format!("{PREFIX}_{day}/sample.txt")).unwrap()
+}
+
+days! {
+    day_01,
+    day_02,
+    day_03,
+    day_04,
+    day_05,
+    day_06,
+    day_07,
+    day_08,
+    day_09,
+    day_10,
+    day_11,
+    day_12,
+}
@@ -0,0 +1,1168 @@
+use crate::bench::Bench;
+use crate::helpers::Input;
+use crate::report::Format;
+use std::fmt::{Display, Formatter};
+use std::time::Duration;
+
+pub const USAGE: &str = "\
+Usage: all [--year YEAR] [DAYS] [--part 1|2|both] [--sample] [--format FORMAT] [--bench N]
+           [--record] [--jobs N] [--timeout DURATION] [--day-timeout DURATION]
+           [--bench N --readme [--redact]]
+       all [--year YEAR] DAY --input <PATH|->
+       all new [--year YEAR] DAY --name TITLE
+       all compare [--baseline COMMIT] [--threshold PERCENT]
+       all watch [--year YEAR] DAY
+       all fetch [--year YEAR] [DAYS]
+       all submit [--year YEAR] DAY PART [--force]
+       all sample [--year YEAR] DAY [--page PATH] [--force]
+       all encrypt|decrypt [--year YEAR] [DAYS] [--new-key]
+       all dashboard [--year YEAR]
+       all --list
+       all --help
+
+DAYS is a comma separated list of days and ranges, e.g. `3,5,7` or `1-6`.
+Omitting DAYS (or passing `0`) runs every registered day of the year.
+YEAR defaults to the latest year with registered days.
+`new` creates src/challenges/year_YEAR/day_DAY from the template and registers it.
+`compare` diffs the latest timings in .aoc/history.csv against an earlier run.
+`watch` reruns a day's sample tests and input whenever its directory changes.
+`fetch` downloads missing puzzle inputs using the session token from AOC_SESSION
+or .aoc/config.toml, and defaults to every registered day of the year.
+`submit` runs one part against the input, posts the answer and logs the outcome
+to .aoc/submissions.csv. Answers that were already rejected, or that fall outside
+the too high/too low bounds of earlier guesses, are refused without --force.
+`sample` extracts the first example block of a puzzle page into sample.txt and the
+highlighted example answers into sample.expected. The page is downloaded unless
+--page points at a saved copy, and existing files are kept without --force.
+`encrypt` replaces each input.txt with an encrypted input.txt.enc, which is read
+transparently using the key in AOC_INPUT_KEY or .aoc/input.key. `decrypt` undoes it.
+--timeout and --day-timeout mark unfinished parts as TIMEOUT and cancel them, and
+a day stops working at its next check of the cancellation token.
+`dashboard` opens an interactive view of the year's days for re-running them and
+reading their captured output.
+
+Options:
+  -y, --year <YEAR>      Run the days registered for YEAR
+  -d, --day <DAYS>       Same as passing DAYS
+  -p, --part <1|2|both>  Only run the given part (default: both)
+  -i, --input <PATH|->   Run a single day against PATH, or stdin for `-`
+  -s, --sample           Run against sample.txt and check sample.expected
+  -f, --format <FORMAT>  Output as pretty, json, csv or ndjson (default: pretty)
+  -b, --bench <N>        Time N iterations per day and report statistics
+  -w, --warmup <N>       Untimed iterations to run before benchmarking (default: 3)
+  -j, --jobs <N>         Run up to N days in parallel (default: 1)
+  -t, --timeout <TIME>   Give up on a part after TIME, e.g. 500ms, 30s or 5m
+      --day-timeout <TIME>
+                         Give up on whatever is left of a day after TIME
+  -r, --record           Accept the current answers into answers.toml
+      --readme           Update the rows of the days run in README.md, requires --bench
+      --redact           Hide the answers in the README table
+  -n, --name <TITLE>     Title of the day created by `new`
+      --baseline <REF>   Compare against the last run at this commit
+                         (default: the previous run that timed any of the same phases)
+      --threshold <PCT>  Flag phases that slowed down by more than PCT percent (default: 10)
+      --force            Submit even if the guess log says the answer is wrong,
+                         or overwrite existing sample files
+      --page <PATH>      Read the puzzle page for `sample` from PATH
+      --new-key          Create .aoc/input.key before encrypting
+  -l, --list             List the registered days
+  -h, --help             Print this message";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Run(RunOptions),
+    New {
+        year: usize,
+        day: usize,
+        name: String,
+    },
+    Compare {
+        baseline: Option<String>,
+        threshold: f64,
+    },
+    Watch {
+        year: usize,
+        day: usize,
+    },
+    Fetch {
+        year: usize,
+        days: Vec<usize>,
+    },
+    Submit {
+        year: usize,
+        day: usize,
+        part: u8,
+        force: bool,
+    },
+    Sample {
+        year: usize,
+        day: usize,
+        page: Option<String>,
+        force: bool,
+    },
+    Crypt {
+        mode: CryptMode,
+        year: usize,
+        days: Vec<usize>,
+    },
+    Dashboard {
+        year: usize,
+    },
+    List,
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct RunOptions {
+    pub year: usize,
+    pub days: Vec<usize>,
+    pub part: Part,
+    pub input: Input,
+    pub format: Format,
+    pub bench: Option<Bench>,
+    pub record: bool,
+    pub readme: bool,
+    pub redact: bool,
+    pub jobs: usize,
+    pub timeout: Option<Duration>,
+    pub day_timeout: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CryptMode {
+    Encrypt { new_key: bool },
+    Decrypt,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Part {
+    Easy,
+    Hard,
+    Both,
+}
+
+impl Part {
+    pub fn includes_easy(&self) -> bool {
+        *self != Part::Hard
+    }
+
+    pub fn includes_hard(&self) -> bool {
+        *self != Part::Easy
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CliError(String);
+
+impl Display for CliError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub fn parse<I>(args: I, registered: &[(usize, usize)]) -> Result<Command, CliError>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter();
+    let command = args.next();
+    match command.as_deref() {
+        Some("new") => parse_new(Args::new(args), registered),
+        Some("compare") => parse_compare(Args::new(args)),
+        Some("watch") => parse_watch(Args::new(args), registered),
+        Some("fetch") => parse_fetch(Args::new(args), registered),
+        Some("submit") => parse_submit(Args::new(args), registered),
+        Some("sample") => parse_sample(Args::new(args), registered),
+        Some("encrypt") => {
+            let mode = CryptMode::Encrypt { new_key: false };
+            parse_crypt(Args::new(args), registered, mode)
+        }
+        Some("decrypt") => parse_crypt(Args::new(args), registered, CryptMode::Decrypt),
+        Some("dashboard") => parse_dashboard(Args::new(args), registered),
+        _ => parse_run(Args::new(command.into_iter().chain(args)), registered),
+    }
+}
+
+fn parse_run<I>(mut args: Args<I>, registered: &[(usize, usize)]) -> Result<Command, CliError>
+where
+    I: Iterator<Item = String>,
+{
+    let mut specs: Vec<String> = Vec::new();
+    let mut part = Part::Both;
+    let mut input = Input::Default;
+    let mut sample = false;
+    let mut format = Format::Pretty;
+    let mut iterations = None;
+    let mut warmup = 3;
+    let mut record = false;
+    let mut readme = false;
+    let mut redact = false;
+    let mut jobs = 1;
+    let mut timeout = None;
+    let mut day_timeout = None;
+    let mut list = false;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-l" | "--list" => list = true,
+            "-y" | "--year" => args.year(&arg)?,
+            "-d" | "--day" => specs.push(args.value(&arg)?),
+            "-p" | "--part" => {
+                part = parse_part(&args.value(&arg)?)?;
+            }
+            "-s" | "--sample" => sample = true,
+            "-r" | "--record" => record = true,
+            "--readme" => readme = true,
+            "--redact" => redact = true,
+            "-t" | "--timeout" => {
+                timeout = Some(parse_duration(&args.value(&arg)?)?);
+            }
+            "--day-timeout" => {
+                let value = args.value(&arg)?;
+                day_timeout = Some(parse_duration(&value)?);
+            }
+            "-j" | "--jobs" => {
+                let value = args.value(&arg)?;
+                jobs = parse_count(&arg, &value, 1)?;
+            }
+            "-f" | "--format" => {
+                format = parse_format(&args.value(&arg)?)?;
+            }
+            "-b" | "--bench" => {
+                let value = args.value(&arg)?;
+                iterations = Some(parse_count(&arg, &value, 1)?);
+            }
+            "-w" | "--warmup" => {
+                let value = args.value(&arg)?;
+                warmup = parse_count(&arg, &value, 0)?;
+            }
+            "-i" | "--input" => {
+                let value = args.value(&arg)?;
+                input = match value.as_str() {
+                    "-" => Input::Stdin,
+                    _ => Input::File(value),
+                };
+            }
+            _ if arg.starts_with('-') => {
+                return Err(CliError(format!("Unknown option `{arg}`")));
+            }
+            _ => specs.push(arg),
+        }
+    }
+
+    if list {
+        return Ok(Command::List);
+    }
+    let (year, registered) = year_days(args.year, registered)?;
+    let mut days: Vec<usize> = Vec::new();
+    for spec in &specs {
+        days.extend(parse_days(spec, &registered)?);
+    }
+    if days.is_empty() {
+        days.extend(registered);
+    }
+    days.sort_unstable();
+    days.dedup();
+    if input != Input::Default && days.len() != 1 {
+        return Err(CliError(
+            "--input can only be used with a single day".to_string(),
+        ));
+    }
+    if sample {
+        if input != Input::Default {
+            return Err(CliError(
+                "--sample cannot be combined with --input".to_string(),
+            ));
+        }
+        input = Input::Sample;
+    }
+    if record && input != Input::Default {
+        return Err(CliError(
+            "--record can only be used with the default input".to_string(),
+        ));
+    }
+    if readme && (input != Input::Default || record) {
+        return Err(CliError(
+            "--readme can only be used with the default input and without --record".to_string(),
+        ));
+    }
+    if readme && iterations.is_none() {
+        return Err(CliError(
+            "--readme requires --bench so the table shows median timings".to_string(),
+        ));
+    }
+    if redact && !readme {
+        return Err(CliError("--redact requires --readme".to_string()));
+    }
+    let bench = iterations.map(|iterations| Bench { iterations, warmup });
+    Ok(Command::Run(RunOptions {
+        year,
+        days,
+        part,
+        input,
+        format,
+        bench,
+        record,
+        readme,
+        redact,
+        jobs,
+        timeout,
+        day_timeout,
+    }))
+}
+
+fn parse_new<I>(mut args: Args<I>, registered: &[(usize, usize)]) -> Result<Command, CliError>
+where
+    I: Iterator<Item = String>,
+{
+    let mut day = None;
+    let mut name = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-n" | "--name" => name = Some(args.value(&arg)?),
+            "-y" | "--year" => args.year(&arg)?,
+            _ if arg.starts_with('-') => {
+                return Err(CliError(format!("Unknown option `{arg}` for new")));
+            }
+            _ if day.is_some() => {
+                return Err(CliError("new takes a single day".to_string()));
+            }
+            _ => {
+                day = Some(
+                    arg.parse::<usize>()
+                        .ok()
+                        .filter(|day| (1..=25).contains(day))
+                        .ok_or_else(|| {
+                            CliError(format!("Invalid day `{arg}`, expected 1 to 25"))
+                        })?,
+                );
+            }
+        }
+    }
+    let year = match args.year {
+        Some(year) => year,
+        None => year_days(None, registered)?.0,
+    };
+    match (day, name) {
+        (Some(day), Some(name)) => Ok(Command::New { year, day, name }),
+        (None, _) => Err(CliError("new requires a day".to_string())),
+        (_, None) => Err(CliError("new requires a --name".to_string())),
+    }
+}
+
+fn parse_compare<I>(mut args: Args<I>) -> Result<Command, CliError>
+where
+    I: Iterator<Item = String>,
+{
+    let mut baseline = None;
+    let mut threshold = 10.0;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "--baseline" => baseline = Some(args.value(&arg)?),
+            "--threshold" => {
+                let value = args.value(&arg)?;
+                threshold = value
+                    .trim_end_matches('%')
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|t| *t >= 0.0)
+                    .ok_or_else(|| {
+                        CliError(format!(
+                            "Invalid threshold `{value}`, expected e.g. 10 or 5%"
+                        ))
+                    })?;
+            }
+            _ => return Err(CliError(format!("Unknown argument `{arg}` for compare"))),
+        }
+    }
+    Ok(Command::Compare {
+        baseline,
+        threshold,
+    })
+}
+
+fn parse_watch<I>(mut args: Args<I>, registered: &[(usize, usize)]) -> Result<Command, CliError>
+where
+    I: Iterator<Item = String>,
+{
+    let mut specs = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-y" | "--year" => args.year(&arg)?,
+            "-d" | "--day" => specs.push(args.value(&arg)?),
+            _ if arg.starts_with('-') => {
+                return Err(CliError(format!("Unknown option `{arg}` for watch")));
+            }
+            _ => specs.push(arg),
+        }
+    }
+    let (year, registered) = year_days(args.year, registered)?;
+    match specs.as_slice() {
+        [spec] => match parse_days(spec, &registered)?.as_slice() {
+            [day] => Ok(Command::Watch { year, day: *day }),
+            _ => Err(CliError("watch takes a single day".to_string())),
+        },
+        _ => Err(CliError("watch takes a single day".to_string())),
+    }
+}
+
+fn parse_fetch<I>(mut args: Args<I>, registered: &[(usize, usize)]) -> Result<Command, CliError>
+where
+    I: Iterator<Item = String>,
+{
+    let mut specs = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-y" | "--year" => args.year(&arg)?,
+            "-d" | "--day" => specs.push(args.value(&arg)?),
+            _ if arg.starts_with('-') => {
+                return Err(CliError(format!("Unknown option `{arg}` for fetch")));
+            }
+            _ => specs.push(arg),
+        }
+    }
+    if specs.is_empty() {
+        let (year, days) = year_days(args.year, registered)?;
+        return Ok(Command::Fetch { year, days });
+    }
+    let year = match args.year {
+        Some(year) => year,
+        None => year_days(None, registered)?.0,
+    };
+    let mut days = Vec::new();
+    for spec in &specs {
+        days.extend(parse_days(spec, &(1..=25).collect::<Vec<usize>>())?);
+    }
+    days.sort_unstable();
+    days.dedup();
+    Ok(Command::Fetch { year, days })
+}
+
+fn parse_submit<I>(mut args: Args<I>, registered: &[(usize, usize)]) -> Result<Command, CliError>
+where
+    I: Iterator<Item = String>,
+{
+    let mut positional = Vec::new();
+    let mut force = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-y" | "--year" => args.year(&arg)?,
+            "--force" => force = true,
+            _ if arg.starts_with('-') => {
+                return Err(CliError(format!("Unknown option `{arg}` for submit")));
+            }
+            _ => positional.push(arg),
+        }
+    }
+    let (year, registered) = year_days(args.year, registered)?;
+    let [day, part] = positional.as_slice() else {
+        return Err(CliError("submit takes a day and a part".to_string()));
+    };
+    let day = match parse_days(day, &registered)?.as_slice() {
+        [day] => *day,
+        _ => return Err(CliError("submit takes a single day".to_string())),
+    };
+    let part = match parse_part(part)? {
+        Part::Easy => 1,
+        Part::Hard => 2,
+        Part::Both => return Err(CliError("submit takes part 1 or 2".to_string())),
+    };
+    Ok(Command::Submit {
+        year,
+        day,
+        part,
+        force,
+    })
+}
+
+fn parse_sample<I>(mut args: Args<I>, registered: &[(usize, usize)]) -> Result<Command, CliError>
+where
+    I: Iterator<Item = String>,
+{
+    let mut specs = Vec::new();
+    let mut page = None;
+    let mut force = false;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-y" | "--year" => args.year(&arg)?,
+            "-d" | "--day" => specs.push(args.value(&arg)?),
+            "--page" => page = Some(args.value(&arg)?),
+            "--force" => force = true,
+            _ if arg.starts_with('-') => {
+                return Err(CliError(format!("Unknown option `{arg}` for sample")));
+            }
+            _ => specs.push(arg),
+        }
+    }
+    let (year, registered) = year_days(args.year, registered)?;
+    match specs.as_slice() {
+        [spec] => match parse_days(spec, &registered)?.as_slice() {
+            [day] => Ok(Command::Sample {
+                year,
+                day: *day,
+                page,
+                force,
+            }),
+            _ => Err(CliError("sample takes a single day".to_string())),
+        },
+        _ => Err(CliError("sample takes a single day".to_string())),
+    }
+}
+
+fn parse_crypt<I>(
+    mut args: Args<I>,
+    registered: &[(usize, usize)],
+    mut mode: CryptMode,
+) -> Result<Command, CliError>
+where
+    I: Iterator<Item = String>,
+{
+    let mut specs = Vec::new();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-y" | "--year" => args.year(&arg)?,
+            "-d" | "--day" => specs.push(args.value(&arg)?),
+            "--new-key" if mode != CryptMode::Decrypt => {
+                mode = CryptMode::Encrypt { new_key: true };
+            }
+            _ if arg.starts_with('-') => {
+                let command = match mode {
+                    CryptMode::Encrypt { .. } => "encrypt",
+                    CryptMode::Decrypt => "decrypt",
+                };
+                return Err(CliError(format!("Unknown option `{arg}` for {command}")));
+            }
+            _ => specs.push(arg),
+        }
+    }
+    let (year, registered) = year_days(args.year, registered)?;
+    let mut days = Vec::new();
+    for spec in &specs {
+        days.extend(parse_days(spec, &registered)?);
+    }
+    if days.is_empty() {
+        days.extend(registered);
+    }
+    days.sort_unstable();
+    days.dedup();
+    Ok(Command::Crypt { mode, year, days })
+}
+
+fn parse_dashboard<I>(mut args: Args<I>, registered: &[(usize, usize)]) -> Result<Command, CliError>
+where
+    I: Iterator<Item = String>,
+{
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-y" | "--year" => args.year(&arg)?,
+            _ => return Err(CliError(format!("Unknown argument `{arg}` for dashboard"))),
+        }
+    }
+    let (year, _) = year_days(args.year, registered)?;
+    Ok(Command::Dashboard { year })
+}
+
+fn parse_year(value: &str) -> Result<usize, CliError> {
+    value
+        .parse::<usize>()
+        .ok()
+        .filter(|year| *year >= 2015)
+        .ok_or_else(|| CliError(format!("Invalid year `{value}`, expected 2015 or later")))
+}
+
+fn year_days(
+    year: Option<usize>,
+    registered: &[(usize, usize)],
+) -> Result<(usize, Vec<usize>), CliError> {
+    let year = match year {
+        Some(year) => year,
+        None => registered
+            .iter()
+            .map(|(year, _)| *year)
+            .max()
+            .ok_or_else(|| CliError("No days are registered".to_string()))?,
+    };
+    let days = registered
+        .iter()
+        .filter(|(y, _)| *y == year)
+        .map(|(_, day)| *day)
+        .collect::<Vec<usize>>();
+    if days.is_empty() {
+        return Err(CliError(format!("No days are registered for {year}")));
+    }
+    Ok((year, days))
+}
+
+struct Args<I> {
+    args: I,
+    inline_value: Option<String>,
+    year: Option<usize>,
+}
+
+impl<I> Args<I>
+where
+    I: Iterator<Item = String>,
+{
+    fn new(args: I) -> Self {
+        Args {
+            args,
+            inline_value: None,
+            year: None,
+        }
+    }
+
+    fn next(&mut self) -> Option<String> {
+        let arg = self.args.next()?;
+        match arg.split_once('=') {
+            Some((flag, value)) if arg.starts_with("--") => {
+                self.inline_value = Some(value.to_string());
+                Some(flag.to_string())
+            }
+            _ => {
+                self.inline_value = None;
+                Some(arg)
+            }
+        }
+    }
+
+    fn value(&mut self, flag: &str) -> Result<String, CliError> {
+        self.inline_value
+            .take()
+            .or_else(|| self.args.next())
+            .ok_or_else(|| CliError(format!("{flag} requires a value")))
+    }
+
+    fn year(&mut self, flag: &str) -> Result<(), CliError> {
+        self.year = Some(parse_year(&self.value(flag)?)?);
+        Ok(())
+    }
+}
+
+fn parse_part(value: &str) -> Result<Part, CliError> {
+    match value {
+        "1" => Ok(Part::Easy),
+        "2" => Ok(Part::Hard),
+        "both" => Ok(Part::Both),
+        _ => Err(CliError(format!(
+            "Invalid part `{value}`, expected 1, 2 or both"
+        ))),
+    }
+}
+
+fn parse_count(flag: &str, value: &str, min: usize) -> Result<usize, CliError> {
+    value
+        .parse::<usize>()
+        .ok()
+        .filter(|count| *count >= min)
+        .ok_or_else(|| {
+            CliError(format!(
+                "{flag} expects a number of at least {min}, got `{value}`"
+            ))
+        })
+}
+
+fn parse_duration(value: &str) -> Result<Duration, CliError> {
+    let split = value
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(value.len());
+    let (amount, unit) = value.split_at(split);
+    let amount = amount.parse::<f64>().ok().filter(|a| *a > 0.0);
+    let scale = match unit {
+        "ms" => Some(0.001),
+        "s" | "" => Some(1.0),
+        "m" => Some(60.0),
+        "h" => Some(3600.0),
+        _ => None,
+    };
+    match (amount, scale) {
+        (Some(amount), Some(scale)) => Duration::try_from_secs_f64(amount * scale)
+            .map_err(|_| CliError(format!("Duration `{value}` is too long"))),
+        _ => Err(CliError(format!(
+            "Invalid duration `{value}`, expected e.g. 500ms, 30s or 5m"
+        ))),
+    }
+}
+
+fn parse_format(value: &str) -> Result<Format, CliError> {
+    match value {
+        "pretty" => Ok(Format::Pretty),
+        "json" => Ok(Format::Json),
+        "csv" => Ok(Format::Csv),
+        "ndjson" => Ok(Format::Ndjson),
+        _ => Err(CliError(format!(
+            "Invalid format `{value}`, expected pretty, json, csv or ndjson"
+        ))),
+    }
+}
+
+fn parse_days(value: &str, registered: &[usize]) -> Result<Vec<usize>, CliError> {
+    if value == "0" {
+        return Ok(registered.to_vec());
+    }
+    let mut days = Vec::new();
+    for item in value.split(',') {
+        match item.split_once('-') {
+            Some((start, end)) => {
+                let start = parse_day(start)?;
+                let end = parse_day(end)?;
+                if start > end {
+                    return Err(CliError(format!(
+                        "Invalid range `{item}`, {start} is after {end}"
+                    )));
+                }
+                let range = registered
+                    .iter()
+                    .filter(|day| (start..=end).contains(*day))
+                    .collect::<Vec<&usize>>();
+                if range.is_empty() {
+                    return Err(CliError(format!("No days are registered in `{item}`")));
+                }
+                days.extend(range);
+            }
+            None => {
+                let day = parse_day(item)?;
+                if !registered.contains(&day) {
+                    return Err(CliError(format!("Day {day} is not registered")));
+                }
+                days.push(day);
+            }
+        }
+    }
+    Ok(days)
+}
+
+fn parse_day(value: &str) -> Result<usize, CliError> {
+    value
+        .trim()
+        .parse::<usize>()
+        .map_err(|_| CliError(format!("Invalid day `{value}`")))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::Bench;
+    use crate::cli::{parse, CliError, Command, CryptMode, Part, RunOptions};
+    use crate::helpers::Input;
+    use crate::report::Format;
+    use std::time::Duration;
+
+    fn run(args: &[&str]) -> Result<Command, CliError> {
+        parse(
+            args.iter().map(|x| x.to_string()),
+            &(1..=12).map(|day| (2025, day)).collect::<Vec<_>>(),
+        )
+    }
+
+    fn days(args: &[&str]) -> Vec<usize> {
+        match run(args).unwrap() {
+            Command::Run(RunOptions { days, .. }) => days,
+            command => panic!("Expected a run command, got {command:?}"),
+        }
+    }
+
+    #[test]
+    fn test_no_args_runs_everything() {
+        assert_eq!(
+            run(&[]).unwrap(),
+            Command::Run(RunOptions {
+                year: 2025,
+                days: (1..=12).collect(),
+                part: Part::Both,
+                input: Input::Default,
+                format: Format::Pretty,
+                bench: None,
+                record: false,
+                readme: false,
+                redact: false,
+                jobs: 1,
+                timeout: None,
+                day_timeout: None,
+            })
+        );
+        assert_eq!(days(&["0"]), (1..=12).collect::<Vec<usize>>());
+    }
+
+    #[test]
+    fn test_day_lists_and_ranges() {
+        assert_eq!(days(&["3"]), vec![3]);
+        assert_eq!(days(&["3,5,7"]), vec![3, 5, 7]);
+        assert_eq!(days(&["1-6"]), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(days(&["7,1-3", "2"]), vec![1, 2, 3, 7]);
+    }
+
+    #[test]
+    fn test_days_with_gaps() {
+        let parse_with = |args: &[&str], registered: &[(usize, usize)]| match parse(
+            args.iter().map(|x| x.to_string()),
+            registered,
+        ) {
+            Ok(Command::Run(RunOptions { days, .. })) => Ok(days),
+            Ok(command) => panic!("Expected a run command, got {command:?}"),
+            Err(e) => Err(e),
+        };
+        let registered = [(2025, 1), (2025, 2), (2025, 5), (2025, 9)];
+        assert_eq!(parse_with(&[], &registered), Ok(vec![1, 2, 5, 9]));
+        assert_eq!(parse_with(&["1-6"], &registered), Ok(vec![1, 2, 5]));
+        assert_eq!(parse_with(&["9,5"], &registered), Ok(vec![5, 9]));
+        assert!(parse_with(&["3"], &registered).is_err());
+        assert!(parse_with(&["3-4"], &registered).is_err());
+    }
+
+    #[test]
+    fn test_years() {
+        let registered = [(2024, 1), (2024, 25), (2025, 1), (2025, 3)];
+        let run_with = |args: &[&str]| match parse(args.iter().map(|x| x.to_string()), &registered)
+        {
+            Ok(Command::Run(RunOptions { year, days, .. })) => Ok((year, days)),
+            Ok(command) => panic!("Expected a run command, got {command:?}"),
+            Err(e) => Err(e),
+        };
+        assert_eq!(run_with(&[]), Ok((2025, vec![1, 3])));
+        assert_eq!(run_with(&["--year", "2024"]), Ok((2024, vec![1, 25])));
+        assert_eq!(
+            run_with(&["-y", "2024", "--day", "25"]),
+            Ok((2024, vec![25]))
+        );
+        assert_eq!(run_with(&["--day=3", "--year=2025"]), Ok((2025, vec![3])));
+        assert!(run_with(&["--year", "2024", "3"]).is_err());
+        assert!(run_with(&["--year", "2023"]).is_err());
+        assert!(run_with(&["--year", "1999"]).is_err());
+    }
+
+    #[test]
+    fn test_part_selection() {
+        let part = |args: &[&str]| match run(args).unwrap() {
+            Command::Run(RunOptions { part, .. }) => part,
+            command => panic!("Expected a run command, got {command:?}"),
+        };
+        assert_eq!(part(&["4", "--part", "1"]), Part::Easy);
+        assert_eq!(part(&["-p", "2"]), Part::Hard);
+        assert_eq!(part(&["--part=both", "4"]), Part::Both);
+    }
+
+    #[test]
+    fn test_input_override() {
+        let input = |args: &[&str]| match run(args).unwrap() {
+            Command::Run(RunOptions { input, .. }) => input,
+            command => panic!("Expected a run command, got {command:?}"),
+        };
+        assert_eq!(input(&["3"]), Input::Default);
+        assert_eq!(
+            input(&["3", "--input", "in.txt"]),
+            Input::File("in.txt".to_string())
+        );
+        assert_eq!(input(&["-i", "-", "3"]), Input::Stdin);
+        assert!(run(&["--input", "in.txt"]).is_err());
+        assert!(run(&["1-2", "--input", "-"]).is_err());
+        assert_eq!(input(&["--sample"]), Input::Sample);
+        assert_eq!(input(&["2", "-s"]), Input::Sample);
+        assert!(run(&["3", "--sample", "--input", "-"]).is_err());
+    }
+
+    #[test]
+    fn test_format() {
+        let format = |args: &[&str]| match run(args).unwrap() {
+            Command::Run(RunOptions { format, .. }) => format,
+            command => panic!("Expected a run command, got {command:?}"),
+        };
+        assert_eq!(format(&["--format", "json"]), Format::Json);
+        assert_eq!(format(&["-f", "csv"]), Format::Csv);
+        assert_eq!(format(&["--format=ndjson"]), Format::Ndjson);
+        assert!(run(&["--format", "xml"]).is_err());
+    }
+
+    #[test]
+    fn test_bench() {
+        let bench = |args: &[&str]| match run(args).unwrap() {
+            Command::Run(RunOptions { bench, .. }) => bench,
+            command => panic!("Expected a run command, got {command:?}"),
+        };
+        assert_eq!(
+            bench(&["--bench", "10"]),
+            Some(Bench {
+                iterations: 10,
+                warmup: 3
+            })
+        );
+        assert_eq!(
+            bench(&["-b", "5", "--warmup", "0"]),
+            Some(Bench {
+                iterations: 5,
+                warmup: 0
+            })
+        );
+        assert!(run(&["--bench", "0"]).is_err());
+        assert!(run(&["--bench", "x"]).is_err());
+    }
+
+    #[test]
+    fn test_record() {
+        assert!(matches!(
+            run(&["--record"]).unwrap(),
+            Command::Run(RunOptions { record: true, .. })
+        ));
+        assert!(run(&["--record", "--sample"]).is_err());
+        assert!(run(&["3", "--record", "--input", "-"]).is_err());
+    }
+
+    #[test]
+    fn test_readme() {
+        assert!(matches!(
+            run(&["1-8", "--bench", "5", "--readme", "--redact"]).unwrap(),
+            Command::Run(RunOptions {
+                readme: true,
+                redact: true,
+                ..
+            })
+        ));
+        assert!(run(&["--redact"]).is_err());
+        assert!(run(&["--readme"]).is_err());
+        assert!(run(&["--readme", "--sample"]).is_err());
+        assert!(run(&["--readme", "--record"]).is_err());
+    }
+
+    #[test]
+    fn test_jobs() {
+        assert!(matches!(
+            run(&["--jobs", "4"]).unwrap(),
+            Command::Run(RunOptions { jobs: 4, .. })
+        ));
+        assert!(run(&["-j", "0"]).is_err());
+    }
+
+    #[test]
+    fn test_timeout() {
+        let timeout = |args: &[&str]| match run(args).unwrap() {
+            Command::Run(RunOptions { timeout, .. }) => timeout,
+            command => panic!("Expected a run command, got {command:?}"),
+        };
+        assert_eq!(timeout(&[]), None);
+        assert_eq!(timeout(&["-t", "500ms"]), Some(Duration::from_millis(500)));
+        assert_eq!(timeout(&["--timeout", "30"]), Some(Duration::from_secs(30)));
+        assert_eq!(
+            timeout(&["--timeout=1.5s"]),
+            Some(Duration::from_millis(1500))
+        );
+        assert_eq!(timeout(&["-t", "2m"]), Some(Duration::from_secs(120)));
+        assert!(run(&["-t", "soon"]).is_err());
+        assert!(run(&["-t", "0s"]).is_err());
+        assert!(run(&["-t", "5d"]).is_err());
+        assert!(run(&["-t", "99999999999999999999999h"]).is_err());
+        match run(&["--day-timeout", "1m", "-t", "5s"]).unwrap() {
+            Command::Run(RunOptions {
+                timeout,
+                day_timeout,
+                ..
+            }) => assert_eq!(
+                (timeout, day_timeout),
+                (Some(Duration::from_secs(5)), Some(Duration::from_secs(60)))
+            ),
+            command => panic!("Expected a run command, got {command:?}"),
+        }
+    }
+
+    #[test]
+    fn test_list_and_help() {
+        assert_eq!(run(&["--list"]).unwrap(), Command::List);
+        assert_eq!(run(&["3", "--help"]).unwrap(), Command::Help);
+    }
+
+    #[test]
+    fn test_new() {
+        assert_eq!(
+            run(&["new", "13", "--name", "Lobby"]).unwrap(),
+            Command::New {
+                year: 2025,
+                day: 13,
+                name: "Lobby".to_string()
+            }
+        );
+        assert_eq!(
+            run(&["new", "--name=Movie Theater", "9", "--year", "2026"]).unwrap(),
+            Command::New {
+                year: 2026,
+                day: 9,
+                name: "Movie Theater".to_string()
+            }
+        );
+        assert!(run(&["new", "13"]).is_err());
+        assert!(run(&["new", "--name", "Lobby"]).is_err());
+        assert!(run(&["new", "26", "-n", "Lobby"]).is_err());
+        assert!(run(&["new", "13", "14", "-n", "Lobby"]).is_err());
+        assert!(run(&["3", "new"]).is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(
+            run(&["compare"]).unwrap(),
+            Command::Compare {
+                baseline: None,
+                threshold: 10.0
+            }
+        );
+        assert_eq!(
+            run(&["compare", "--baseline", "abc123", "--threshold=2.5%"]).unwrap(),
+            Command::Compare {
+                baseline: Some("abc123".to_string()),
+                threshold: 2.5
+            }
+        );
+        assert!(run(&["compare", "--threshold", "-5"]).is_err());
+        assert!(run(&["compare", "3"]).is_err());
+    }
+
+    #[test]
+    fn test_watch() {
+        assert_eq!(
+            run(&["watch", "4"]).unwrap(),
+            Command::Watch { year: 2025, day: 4 }
+        );
+        assert_eq!(
+            run(&["watch", "-y", "2025", "--day", "4"]).unwrap(),
+            Command::Watch { year: 2025, day: 4 }
+        );
+        assert!(run(&["watch", "--year", "2024", "4"]).is_err());
+        assert!(run(&["watch"]).is_err());
+        assert!(run(&["watch", "13"]).is_err());
+        assert!(run(&["watch", "1-3"]).is_err());
+        assert!(run(&["watch", "3", "4"]).is_err());
+    }
+
+    #[test]
+    fn test_fetch() {
+        assert_eq!(
+            run(&["fetch"]).unwrap(),
+            Command::Fetch {
+                year: 2025,
+                days: (1..=12).collect()
+            }
+        );
+        assert_eq!(
+            run(&["fetch", "--year", "2024", "3,1-2", "-d", "25"]).unwrap(),
+            Command::Fetch {
+                year: 2024,
+                days: vec![1, 2, 3, 25]
+            }
+        );
+        assert!(run(&["fetch", "--year", "2024"]).is_err());
+        assert!(run(&["fetch", "26"]).is_err());
+        assert!(run(&["fetch", "--sample"]).is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        assert_eq!(
+            run(&["submit", "3", "2"]).unwrap(),
+            Command::Submit {
+                year: 2025,
+                day: 3,
+                part: 2,
+                force: false
+            }
+        );
+        assert_eq!(
+            run(&["submit", "--year=2025", "12", "1", "--force"]).unwrap(),
+            Command::Submit {
+                year: 2025,
+                day: 12,
+                part: 1,
+                force: true
+            }
+        );
+        assert!(run(&["submit", "3"]).is_err());
+        assert!(run(&["submit", "3", "both"]).is_err());
+        assert!(run(&["submit", "1-3", "1"]).is_err());
+        assert!(run(&["submit", "13", "1"]).is_err());
+        assert!(run(&["submit", "3", "1", "2"]).is_err());
+    }
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(
+            run(&["sample", "2"]).unwrap(),
+            Command::Sample {
+                year: 2025,
+                day: 2,
+                page: None,
+                force: false
+            }
+        );
+        assert_eq!(
+            run(&["sample", "--page=day2.html", "-d", "2", "--force"]).unwrap(),
+            Command::Sample {
+                year: 2025,
+                day: 2,
+                page: Some("day2.html".to_string()),
+                force: true
+            }
+        );
+        assert!(run(&["sample"]).is_err());
+        assert!(run(&["sample", "13"]).is_err());
+        assert!(run(&["sample", "2", "--page"]).is_err());
+    }
+
+    #[test]
+    fn test_encrypt_and_decrypt() {
+        assert_eq!(
+            run(&["encrypt"]).unwrap(),
+            Command::Crypt {
+                mode: CryptMode::Encrypt { new_key: false },
+                year: 2025,
+                days: (1..=12).collect(),
+            }
+        );
+        assert_eq!(
+            run(&["encrypt", "3,1", "--new-key"]).unwrap(),
+            Command::Crypt {
+                mode: CryptMode::Encrypt { new_key: true },
+                year: 2025,
+                days: vec![1, 3],
+            }
+        );
+        assert_eq!(
+            run(&["decrypt", "--year", "2025", "-d", "2-4"]).unwrap(),
+            Command::Crypt {
+                mode: CryptMode::Decrypt,
+                year: 2025,
+                days: vec![2, 3, 4],
+            }
+        );
+        assert!(run(&["decrypt", "--new-key"]).is_err());
+        assert!(run(&["encrypt", "13"]).is_err());
+    }
+
+    #[test]
+    fn test_dashboard() {
+        assert_eq!(
+            run(&["dashboard"]).unwrap(),
+            Command::Dashboard { year: 2025 }
+        );
+        assert_eq!(
+            run(&["dashboard", "-y", "2025"]).unwrap(),
+            Command::Dashboard { year: 2025 }
+        );
+        assert!(run(&["dashboard", "--year", "2024"]).is_err());
+        assert!(run(&["dashboard", "3"]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(run(&["foo"]).is_err());
+        assert!(run(&["13"]).is_err());
+        assert!(run(&["6-2"]).is_err());
+        assert!(run(&["1,,2"]).is_err());
+        assert!(run(&["--part", "3"]).is_err());
+        assert!(run(&["--part"]).is_err());
+        assert!(run(&["--bogus"]).is_err());
+    }
+}
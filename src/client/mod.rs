@@ -0,0 +1,246 @@
+use crate::config::Config;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+use ureq::Agent;
+
+pub const USER_AGENT: &str = concat!(
+    "github.com/mtheos/advent-of-code-2025 ",
+    env!("CARGO_PKG_NAME"),
+    "/",
+    env!("CARGO_PKG_VERSION")
+);
+
+#[derive(Debug, PartialEq)]
+pub struct ClientError(String);
+
+impl Display for ClientError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Fetched {
+    Cached,
+    Downloaded,
+}
+
+pub struct Client {
+    agent: Agent,
+    base_url: String,
+    session: String,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        let agent = Agent::config_builder()
+            .user_agent(USER_AGENT)
+            .http_status_as_error(false)
+            .timeout_global(Some(Duration::from_secs(30)))
+            .build()
+            .into();
+        Client {
+            agent,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    pub fn from_config(config: &Config) -> Result<Self, ClientError> {
+        let session = config.session.as_deref().ok_or_else(|| {
+            ClientError(
+                "No session token, set AOC_SESSION or add `session = \"...\"` to .aoc/config.toml"
+                    .to_string(),
+            )
+        })?;
+        Ok(Client::new(config.base_url(), session))
+    }
+
+    pub fn fetch_input(
+        &self,
+        path: &Path,
+        year: usize,
+        day: usize,
+    ) -> Result<Fetched, ClientError> {
+        if fs::metadata(path).is_ok_and(|m| m.len() > 0) {
+            return Ok(Fetched::Cached);
+        }
+        let input = self.get(&format!("/{year}/day/{day}/input"), year, day)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| io_error(dir, e))?;
+        }
+        fs::write(path, input).map_err(|e| io_error(path, e))?;
+        Ok(Fetched::Downloaded)
+    }
+
+    pub fn puzzle(&self, year: usize, day: usize) -> Result<String, ClientError> {
+        self.get(&format!("/{year}/day/{day}"), year, day)
+    }
+
+    pub fn submit(
+        &self,
+        year: usize,
+        day: usize,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, ClientError> {
+        let response = self
+            .agent
+            .post(format!("{}/{year}/day/{day}/answer", self.base_url))
+            .header("Cookie", format!("session={}", self.session))
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)]);
+        read(response, year, day)
+    }
+
+    fn get(&self, path: &str, year: usize, day: usize) -> Result<String, ClientError> {
+        let mut request = self.agent.get(format!("{}{path}", self.base_url));
+        if !self.session.is_empty() {
+            request = request.header("Cookie", format!("session={}", self.session));
+        }
+        read(request.call(), year, day)
+    }
+}
+
+fn read(
+    response: Result<ureq::http::Response<ureq::Body>, ureq::Error>,
+    year: usize,
+    day: usize,
+) -> Result<String, ClientError> {
+    let mut response = response.map_err(|e| ClientError(format!("Request failed: {e}")))?;
+    let status = response.status().as_u16();
+    let body = response
+        .body_mut()
+        .read_to_string()
+        .map_err(|e| ClientError(format!("Could not read the response: {e}")))?;
+    match status {
+        200 => Ok(body),
+        400 | 401 | 403 => Err(ClientError(format!(
+            "The session token was rejected (HTTP {status})"
+        ))),
+        404 => Err(ClientError(format!(
+            "Day {day} of {year} is not available yet (HTTP 404)"
+        ))),
+        _ => Err(ClientError(format!(
+            "Unexpected response (HTTP {status}): {}",
+            body.trim()
+        ))),
+    }
+}
+
+fn io_error(path: &Path, e: std::io::Error) -> ClientError {
+    ClientError(format!("{}: {e}", path.display()))
+}
+
+#[cfg(test)]
+pub mod mock {
+    use std::io::{BufRead, BufReader, Read, Write};
+    use std::net::TcpListener;
+    use std::sync::mpsc::{self, Receiver};
+    use std::thread;
+
+    pub fn serve(responses: Vec<(u16, &str)>) -> (String, Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let responses = responses
+            .into_iter()
+            .map(|(status, body)| (status, body.to_string()))
+            .collect::<Vec<_>>();
+        let (tx, rx) = mpsc::channel();
+        thread::spawn(move || {
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+                let mut request = String::new();
+                let mut length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("content-length") {
+                            length = value.trim().parse().unwrap();
+                        }
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" || line.is_empty() {
+                        break;
+                    }
+                }
+                let mut content = vec![0; length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8_lossy(&content));
+                tx.send(request).unwrap();
+                let response = format!(
+                    "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                );
+                reader.get_mut().write_all(response.as_bytes()).unwrap();
+            }
+        });
+        (url, rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::mock::serve;
+    use crate::client::{Client, Fetched, USER_AGENT};
+    use crate::config::Config;
+    use std::fs;
+    use std::path::PathBuf;
+
+    fn temp_file(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-client-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("2025/day_03.txt")
+    }
+
+    #[test]
+    fn test_fetch_input_downloads_once() {
+        let (url, requests) = serve(vec![(200, "987654321111111\n811111111111119\n")]);
+        let client = Client::new(&url, "secret");
+        let path = temp_file("download");
+
+        assert_eq!(client.fetch_input(&path, 2025, 3), Ok(Fetched::Downloaded));
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "987654321111111\n811111111111119\n"
+        );
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2025/day/3/input HTTP/1.1\r\n"));
+        assert!(request.contains("cookie: session=secret\r\n"));
+        assert!(request.contains(&format!("user-agent: {USER_AGENT}\r\n")));
+
+        assert_eq!(client.fetch_input(&path, 2025, 3), Ok(Fetched::Cached));
+        assert!(requests.try_recv().is_err());
+        fs::remove_dir_all(path.parent().unwrap().parent().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn test_fetch_input_errors() {
+        let (url, _requests) = serve(vec![(400, "Puzzle inputs differ by user."), (404, "")]);
+        let client = Client::new(&url, "expired");
+        let path = temp_file("errors");
+        let error = client.fetch_input(&path, 2025, 3).unwrap_err();
+        assert!(error.to_string().contains("session token was rejected"));
+        let error = client.fetch_input(&path, 2025, 25).unwrap_err();
+        assert!(error.to_string().contains("not available yet"));
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_puzzle_without_session() {
+        let (url, requests) = serve(vec![(200, "<article></article>")]);
+        let client = Client::new(&url, "");
+        assert_eq!(client.puzzle(2025, 1).unwrap(), "<article></article>");
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("GET /2025/day/1 HTTP/1.1\r\n"));
+        assert!(!request.contains("cookie:"));
+    }
+
+    #[test]
+    fn test_missing_session() {
+        assert!(Client::from_config(&Config::default()).is_err());
+    }
+}
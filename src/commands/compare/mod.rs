@@ -0,0 +1,29 @@
+use crate::history::{self, HISTORY_PATH};
+use crate::report::pretty_print;
+use std::process;
+
+pub fn run(baseline: Option<&str>, threshold: f64) {
+    let runs = match history::load(HISTORY_PATH) {
+        Ok(runs) => runs,
+        Err(e) => {
+            eprintln!("error: Could not read {HISTORY_PATH}: {e}");
+            process::exit(1);
+        }
+    };
+    let Some((baseline, latest)) = history::select(&runs, baseline) else {
+        eprintln!("error: No baseline run to compare against in {HISTORY_PATH}");
+        process::exit(1);
+    };
+    let changes = history::compare(baseline, latest);
+    pretty_print(history::comparison_lines(
+        baseline, latest, &changes, threshold,
+    ));
+    let regressions = changes
+        .iter()
+        .filter(|c| c.is_regression(threshold))
+        .count();
+    if regressions > 0 {
+        eprintln!("{regressions} phase(s) regressed by more than {threshold}%");
+        process::exit(1);
+    }
+}
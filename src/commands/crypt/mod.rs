@@ -0,0 +1,43 @@
+use crate::cli::CryptMode;
+use crate::crypto::{self, Key, Migrated};
+use crate::helpers::input_path;
+use std::process;
+
+pub fn run(mode: CryptMode, year: usize, days: &[usize]) {
+    if mode == (CryptMode::Encrypt { new_key: true }) {
+        if let Err(e) = Key::create() {
+            eprintln!("error: {e}");
+            process::exit(2);
+        }
+        eprintln!("Created a new input key, keep a copy somewhere safe");
+    }
+    let key = match Key::load() {
+        Ok(key) => key,
+        Err(e) => {
+            eprintln!("error: {e}");
+            process::exit(2);
+        }
+    };
+    let mut failed = false;
+    for &day in days {
+        let path = input_path(year, day);
+        let (migrated, done) = match mode {
+            CryptMode::Encrypt { .. } => (crypto::encrypt_file(&key, &path), "encrypted"),
+            CryptMode::Decrypt => (crypto::decrypt_file(&key, &path), "decrypted"),
+        };
+        match migrated {
+            Ok(Migrated::Done) => println!("{year} Day {day:02}: {done}"),
+            Ok(Migrated::Missing) => println!("{year} Day {day:02}: no input"),
+            Ok(Migrated::Skipped(reason)) => {
+                println!("{year} Day {day:02}: skipped, {reason}")
+            }
+            Err(e) => {
+                eprintln!("{year} Day {day:02}: {e}");
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
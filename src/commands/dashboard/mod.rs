@@ -0,0 +1,26 @@
+use crate::answers::{AnswerStore, ANSWERS_PATH};
+use crate::challenges::Registry;
+use crate::dashboard::{self, Dashboard};
+use crate::history::{self, HISTORY_PATH};
+use std::process;
+
+pub fn run(registry: &Registry, year: usize) {
+    let answers = AnswerStore::load(ANSWERS_PATH).unwrap_or_else(|e| {
+        eprintln!("warning: Could not read {ANSWERS_PATH}: {e}");
+        AnswerStore::default()
+    });
+    let runs = history::load(HISTORY_PATH).unwrap_or_else(|e| {
+        eprintln!("warning: Could not read {HISTORY_PATH}: {e}");
+        Vec::new()
+    });
+    let days = registry
+        .iter()
+        .filter(|registration| registration.year == year)
+        .map(|registration| (registration.day, registration.title))
+        .collect::<Vec<_>>();
+    let rows = dashboard::rows(year, &days, &answers, &runs);
+    if let Err(e) = dashboard::run(Dashboard::new(year, rows)) {
+        eprintln!("error: {e}");
+        process::exit(1);
+    }
+}
@@ -0,0 +1,35 @@
+use crate::client::{Client, Fetched};
+use crate::config::Config;
+use crate::crypto::encrypted_path;
+use crate::helpers::input_path;
+use std::path::Path;
+use std::process;
+
+pub fn run(year: usize, days: &[usize]) {
+    let client = match Client::from_config(Config::get()) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("error: {e}");
+            process::exit(2);
+        }
+    };
+    let mut failed = false;
+    for &day in days {
+        let path = input_path(year, day);
+        if Path::new(&encrypted_path(&path)).exists() {
+            println!("{year} Day {day:02}: already cached (encrypted)");
+            continue;
+        }
+        match client.fetch_input(Path::new(&path), year, day) {
+            Ok(Fetched::Cached) => println!("{year} Day {day:02}: already cached"),
+            Ok(Fetched::Downloaded) => println!("{year} Day {day:02}: saved to {path}"),
+            Err(e) => {
+                eprintln!("{year} Day {day:02}: {e}");
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        process::exit(1);
+    }
+}
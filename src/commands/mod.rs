@@ -0,0 +1,8 @@
+pub mod compare;
+pub mod crypt;
+pub mod dashboard;
+pub mod fetch;
+pub mod new;
+pub mod run;
+pub mod sample;
+pub mod submit;
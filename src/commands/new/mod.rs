@@ -0,0 +1,19 @@
+use crate::helpers::ROOT;
+use crate::scaffold;
+use std::path::Path;
+use std::process;
+
+pub fn run(year: usize, day: usize, name: &str) {
+    match scaffold::create_day(Path::new(ROOT), year, day, name) {
+        Ok(files) => {
+            println!("Created {year} Day {day:02} - {}", name.trim());
+            files
+                .iter()
+                .for_each(|file| println!("  {}", file.display()));
+        }
+        Err(e) => {
+            eprintln!("error: {e}");
+            process::exit(1);
+        }
+    }
+}
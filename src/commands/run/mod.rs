@@ -0,0 +1,167 @@
+use crate::answers::{AnswerStore, Expected, ANSWERS_PATH};
+use crate::challenges::Registry;
+use crate::cli::RunOptions;
+use crate::crypto::{encrypted_path, Key};
+use crate::helpers::{input_path, Input};
+use crate::history::{self, HISTORY_PATH};
+use crate::readme::{self, README_PATH};
+use crate::report::{Printer, Status};
+use crate::runner::{install_panic_hook, run_challenge, run_days, Plan};
+use crate::submit::{self, SUBMISSIONS_PATH};
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process;
+use std::time::Instant;
+
+pub fn run(registry: &Registry, options: RunOptions) {
+    let RunOptions {
+        year,
+        days,
+        part,
+        input,
+        format,
+        bench,
+        record,
+        readme,
+        redact,
+        jobs,
+        timeout,
+        day_timeout,
+    } = options;
+    if let Input::File(path) = &input {
+        if !Path::new(path).is_file() {
+            eprintln!("error: Input file `{path}` does not exist");
+            process::exit(2);
+        }
+    }
+    if input == Input::Default {
+        let encrypted = days.iter().any(|day| {
+            let path = input_path(year, *day);
+            !Path::new(&path).exists() && Path::new(&encrypted_path(&path)).exists()
+        });
+        if let (true, Err(e)) = (encrypted, Key::load()) {
+            eprintln!("error: Some inputs are encrypted. {e}");
+            process::exit(2);
+        }
+    }
+    let mut answers = match AnswerStore::load(ANSWERS_PATH) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("error: Could not read {ANSWERS_PATH}: {e}");
+            process::exit(1);
+        }
+    };
+    install_panic_hook();
+    let mut printer = Printer::new(format, bench.is_some());
+    let mut failed = false;
+    let mut mismatches = Vec::new();
+    let mut recorded = 0;
+    let mut timings = Vec::new();
+    let mut reports = Vec::new();
+    let (timestamp, commit) = (history::timestamp(), history::current_commit());
+    let guesses = match input {
+        Input::Default => submit::load(SUBMISSIONS_PATH).unwrap_or_else(|e| {
+            eprintln!("warning: Could not read {SUBMISSIONS_PATH}: {e}");
+            Vec::new()
+        }),
+        _ => Vec::new(),
+    };
+    let expectations = days
+        .iter()
+        .map(|day| {
+            let expected = match input {
+                Input::Sample => Some(Expected::for_sample(year, *day).unwrap_or_default()),
+                Input::Default if !record => Some(answers.expected(year, *day)),
+                _ => None,
+            };
+            (*day, expected)
+        })
+        .collect::<BTreeMap<usize, Option<Expected>>>();
+    let plan = Plan {
+        year,
+        input: &input,
+        part,
+        bench,
+        timeout,
+        day_timeout,
+    };
+    let start = Instant::now();
+    printer.begin();
+    run_days(
+        &days,
+        jobs,
+        |day| {
+            let expected = expectations[&day].clone();
+            let constructor = registry.get(year, day).unwrap().constructor;
+            run_challenge(day, constructor, &plan, expected)
+        },
+        |report| {
+            if let Status::Invalid(error) = &report.status {
+                eprintln!("{}", error.diagnostic());
+            }
+            report.parts.iter().for_each(|p| {
+                let Some(answer) = p.answer.value().map(|value| value.to_string()) else {
+                    return;
+                };
+                if let Some(warning) =
+                    submit::warning(&guesses, (year, report.day, p.part), &answer)
+                {
+                    eprintln!(
+                        "warning: {year} Day {:02} part {}: {warning}",
+                        report.day, p.part
+                    );
+                }
+            });
+            if !report.passed() {
+                failed = true;
+                if input == Input::Default {
+                    mismatches.extend(report.failures());
+                }
+            }
+            if record {
+                report.parts.iter().for_each(|p| {
+                    if !p.timed_out && answers.record(year, report.day, p.part, &p.answer) {
+                        recorded += 1;
+                    }
+                });
+            }
+            if input == Input::Default {
+                timings.extend(history::entries(&report, year, &timestamp, &commit));
+            }
+            printer.print(&report);
+            if readme {
+                reports.push(report);
+            }
+        },
+    );
+    printer.finish();
+    printer.summary(start.elapsed());
+    if !timings.is_empty() {
+        if let Err(e) = history::append(HISTORY_PATH, &timings) {
+            eprintln!("warning: Could not write {HISTORY_PATH}: {e}");
+        }
+    }
+    if record {
+        if let Err(e) = answers.save() {
+            eprintln!("error: Could not write {ANSWERS_PATH}: {e}");
+            process::exit(1);
+        }
+        eprintln!("Recorded {recorded} answers to {ANSWERS_PATH}");
+    }
+    if readme {
+        if let Err(e) = readme::update(README_PATH, year, &readme::table(&reports, redact), redact)
+        {
+            eprintln!("error: Could not write {README_PATH}: {e}");
+            process::exit(1);
+        }
+        eprintln!("Updated the results table in {README_PATH}");
+    }
+    if !mismatches.is_empty() {
+        eprintln!("!! ANSWER MISMATCH !!");
+        mismatches.iter().for_each(|m| eprintln!("!! {m}"));
+        eprintln!("!! Run with --record if the new answers are correct");
+    }
+    if failed {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,46 @@
+use crate::client::Client;
+use crate::config::Config;
+use crate::helpers::day_dir;
+use crate::puzzle::{self, Saved};
+use std::fs;
+use std::path::Path;
+use std::process;
+
+pub fn run(year: usize, day: usize, page: Option<&str>, force: bool) {
+    let html = match page {
+        Some(path) => fs::read_to_string(path).map_err(|e| format!("{path}: {e}")),
+        None => {
+            let config = Config::get();
+            Client::from_config(config)
+                .unwrap_or_else(|_| Client::new(config.base_url(), ""))
+                .puzzle(year, day)
+                .map_err(|e| e.to_string())
+        }
+    };
+    let html = match html {
+        Ok(html) => html,
+        Err(e) => {
+            eprintln!("error: {e}");
+            process::exit(1);
+        }
+    };
+    let puzzle = puzzle::extract(&html);
+    if puzzle.sample.is_none() {
+        eprintln!("warning: No example block found in the puzzle page");
+    }
+    if puzzle.easy.is_none() && puzzle.hard.is_none() {
+        eprintln!("warning: No highlighted example answers found in the puzzle page");
+    }
+    match puzzle::save(Path::new(&day_dir(year, day)), &puzzle, force) {
+        Ok(saved) => saved.iter().for_each(|(path, saved)| match saved {
+            Saved::Written => println!("Wrote {}", path.display()),
+            Saved::Kept => {
+                println!("Kept {}, pass --force to overwrite it", path.display())
+            }
+        }),
+        Err(e) => {
+            eprintln!("error: {e}");
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use crate::answers::{AnswerStore, ANSWERS_PATH};
+use crate::challenges::Registry;
+use crate::cli::Part;
+use crate::client::Client;
+use crate::config::Config;
+use crate::helpers::Input;
+use crate::history;
+use crate::report::Status;
+use crate::runner::{install_panic_hook, run_challenge, Plan};
+use crate::submit::{self, SUBMISSIONS_PATH};
+use std::process;
+
+pub fn run(registry: &Registry, year: usize, day: usize, part: u8, force: bool) {
+    let client = match Client::from_config(Config::get()) {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("error: {e}");
+            process::exit(2);
+        }
+    };
+    install_panic_hook();
+    let plan = Plan {
+        year,
+        input: &Input::Default,
+        part: if part == 1 { Part::Easy } else { Part::Hard },
+        bench: None,
+        timeout: None,
+        day_timeout: None,
+    };
+    let constructor = registry.get(year, day).unwrap().constructor;
+    let report = run_challenge(day, constructor, &plan, None);
+    if let Status::Invalid(error) = &report.status {
+        eprintln!("{}", error.diagnostic());
+        process::exit(1);
+    }
+    let solution = report.part(part).map(|p| &p.answer);
+    let answer = match (report.status.message(), solution.and_then(|s| s.value())) {
+        (None, Some(value)) => value.to_string(),
+        (Some(message), _) => {
+            eprintln!("error: {year} Day {day:02} failed: {message}");
+            process::exit(1);
+        }
+        _ => {
+            eprintln!("error: {year} Day {day:02} part {part} has no answer to submit");
+            process::exit(1);
+        }
+    };
+    let guesses = submit::load(SUBMISSIONS_PATH).unwrap_or_else(|e| {
+        eprintln!("warning: Could not read {SUBMISSIONS_PATH}: {e}");
+        Vec::new()
+    });
+    if let Some(warning) = submit::warning(&guesses, (year, day, part), &answer) {
+        if !force {
+            eprintln!("error: {year} Day {day:02} part {part}: {warning}");
+            eprintln!("Pass --force to submit it anyway");
+            process::exit(1);
+        }
+        eprintln!("warning: {year} Day {day:02} part {part}: {warning}");
+    }
+    println!("Submitting {year} Day {day:02} part {part}: {answer}");
+    let outcome = match client.submit(year, day, part, &answer) {
+        Ok(body) => submit::parse_outcome(&body),
+        Err(e) => {
+            eprintln!("error: {e}");
+            process::exit(1);
+        }
+    };
+    println!("{outcome}");
+    let timestamp = history::timestamp();
+    if let Err(e) = submit::record(
+        SUBMISSIONS_PATH,
+        &timestamp,
+        (year, day, part),
+        &answer,
+        &outcome,
+    ) {
+        eprintln!("warning: Could not write {SUBMISSIONS_PATH}: {e}");
+    }
+    if outcome == submit::Outcome::Correct {
+        let saved = AnswerStore::load(ANSWERS_PATH).and_then(|mut answers| {
+            answers.record(year, day, part, solution.unwrap());
+            answers.save()
+        });
+        match saved {
+            Ok(()) => eprintln!("Recorded the answer to {ANSWERS_PATH}"),
+            Err(e) => eprintln!("warning: Could not write {ANSWERS_PATH}: {e}"),
+        }
+    }
+    if !outcome.accepted() {
+        process::exit(1);
+    }
+}
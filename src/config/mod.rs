@@ -0,0 +1,105 @@
+use crate::helpers::root_path;
+use std::env;
+use std::fs;
+use std::io;
+use std::sync::OnceLock;
+
+pub const CONFIG_PATH: &str = root_path!("/.aoc/config.toml");
+pub const BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub input_dir: Option<String>,
+    pub base_url: Option<String>,
+}
+
+impl Config {
+    pub fn get() -> &'static Config {
+        static CONFIG: OnceLock<Config> = OnceLock::new();
+        CONFIG.get_or_init(|| {
+            Config::load().unwrap_or_else(|e| {
+                eprintln!("warning: Could not read the config file: {e}");
+                Config::default().with_env(|key| env::var(key).ok())
+            })
+        })
+    }
+
+    pub fn load() -> io::Result<Self> {
+        let path = env::var("AOC_CONFIG").unwrap_or_else(|_| CONFIG_PATH.to_string());
+        let contents = match fs::read_to_string(&path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        Ok(Self::parse(&contents).with_env(|key| env::var(key).ok()))
+    }
+
+    fn parse(contents: &str) -> Self {
+        let mut config = Config::default();
+        contents
+            .lines()
+            .map(|line| line.trim())
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| line.split_once('='))
+            .for_each(|(key, value)| {
+                let value = Some(value.trim().trim_matches('"').to_string());
+                match key.trim() {
+                    "session" => config.session = value,
+                    "input_dir" => config.input_dir = value,
+                    "base_url" => config.base_url = value,
+                    _ => {}
+                }
+            });
+        config
+    }
+
+    fn with_env<F>(mut self, var: F) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        let var = |key| var(key).filter(|value: &String| !value.is_empty());
+        self.session = var("AOC_SESSION").or(self.session);
+        self.input_dir = var("AOC_INPUT_DIR").or(self.input_dir);
+        self.base_url = var("AOC_BASE_URL").or(self.base_url);
+        self
+    }
+
+    pub fn base_url(&self) -> &str {
+        self.base_url
+            .as_deref()
+            .unwrap_or(BASE_URL)
+            .trim_end_matches('/')
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::config::{Config, BASE_URL};
+
+    #[test]
+    fn test_parse() {
+        let config = Config::parse(
+            "# secrets\nsession = \"abc123\"\ninput_dir=\"/tmp/inputs\"\nunknown = 1\n",
+        );
+        assert_eq!(config.session, Some("abc123".to_string()));
+        assert_eq!(config.input_dir, Some("/tmp/inputs".to_string()));
+        assert_eq!(config.base_url(), BASE_URL);
+    }
+
+    #[test]
+    fn test_env_overrides_file() {
+        let config =
+            Config::parse("session = \"file\"\ninput_dir = \"/from/file\"\n").with_env(|key| {
+                match key {
+                    "AOC_SESSION" => Some("env".to_string()),
+                    "AOC_INPUT_DIR" => Some(String::new()),
+                    "AOC_BASE_URL" => Some("http://127.0.0.1:8080/".to_string()),
+                    _ => None,
+                }
+            });
+        assert_eq!(config.session, Some("env".to_string()));
+        assert_eq!(config.input_dir, Some("/from/file".to_string()));
+        assert_eq!(config.base_url(), "http://127.0.0.1:8080");
+    }
+}
@@ -0,0 +1,201 @@
+use crate::helpers::root_path;
+use chacha20poly1305::aead::{Aead, Generate, KeyInit};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use std::env;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub const KEY_PATH: &str = root_path!("/.aoc/input.key");
+
+const MAGIC: &[u8] = b"aoc-enc1";
+const NONCE_LEN: usize = 24;
+
+#[derive(Debug, PartialEq)]
+pub struct CryptoError(String);
+
+impl Display for CryptoError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Migrated {
+    Done,
+    Missing,
+    Skipped(String),
+}
+
+pub struct Key([u8; 32]);
+
+impl Key {
+    pub fn load() -> Result<Self, CryptoError> {
+        if let Some(hex) = env::var("AOC_INPUT_KEY").ok().filter(|k| !k.is_empty()) {
+            return Key::parse(&hex).map_err(|e| CryptoError(format!("AOC_INPUT_KEY: {e}")));
+        }
+        match fs::read_to_string(KEY_PATH) {
+            Ok(hex) => Key::parse(&hex).map_err(|e| CryptoError(format!("{KEY_PATH}: {e}"))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Err(CryptoError(format!(
+                "No input key, set AOC_INPUT_KEY or write a 64 character hex key to {KEY_PATH} \
+(`all encrypt --new-key` creates one)"
+            ))),
+            Err(e) => Err(CryptoError(format!("{KEY_PATH}: {e}"))),
+        }
+    }
+
+    pub fn parse(hex: &str) -> Result<Self, CryptoError> {
+        let hex = hex.trim();
+        let invalid = || CryptoError("expected a key of 64 hex characters".to_string());
+        if hex.len() != 64 || !hex.is_ascii() {
+            return Err(invalid());
+        }
+        let mut key = [0; 32];
+        for (i, byte) in key.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&hex[i * 2..i * 2 + 2], 16).map_err(|_| invalid())?;
+        }
+        Ok(Key(key))
+    }
+
+    pub fn create() -> Result<Self, CryptoError> {
+        if Path::new(KEY_PATH).exists() {
+            return Err(CryptoError(format!("{KEY_PATH} already exists")));
+        }
+        let key = Key(<[u8; 32]>::generate());
+        let hex = key.0.iter().map(|b| format!("{b:02x}")).collect::<String>();
+        if let Some(dir) = Path::new(KEY_PATH).parent() {
+            fs::create_dir_all(dir).map_err(|e| CryptoError(format!("{}: {e}", dir.display())))?;
+        }
+        fs::write(KEY_PATH, format!("{hex}\n"))
+            .map_err(|e| CryptoError(format!("{KEY_PATH}: {e}")))?;
+        Ok(key)
+    }
+
+    fn cipher(&self) -> XChaCha20Poly1305 {
+        XChaCha20Poly1305::new(&self.0.into())
+    }
+}
+
+pub fn encrypted_path(path: &str) -> String {
+    format!("{path}.enc")
+}
+
+pub fn encrypt(key: &Key, plaintext: &[u8]) -> Vec<u8> {
+    let nonce = XNonce::generate();
+    let ciphertext = key
+        .cipher()
+        .encrypt(&nonce, plaintext)
+        .expect("encrypting an in-memory buffer cannot fail");
+    [MAGIC, nonce.as_slice(), &ciphertext].concat()
+}
+
+pub fn decrypt(key: &Key, blob: &[u8]) -> Result<Vec<u8>, CryptoError> {
+    let Some(rest) = blob.strip_prefix(MAGIC).filter(|r| r.len() >= NONCE_LEN) else {
+        return Err(CryptoError("not an encrypted input".to_string()));
+    };
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+    let nonce = XNonce::try_from(nonce).expect("nonce has the right length");
+    key.cipher()
+        .decrypt(&nonce, ciphertext)
+        .map_err(|_| CryptoError("could not decrypt, is the input key correct?".to_string()))
+}
+
+pub fn read_encrypted(key: &Key, path: &str) -> Result<String, CryptoError> {
+    let blob = fs::read(path).map_err(|e| CryptoError(format!("{path}: {e}")))?;
+    let plaintext = decrypt(key, &blob).map_err(|e| CryptoError(format!("{path}: {e}")))?;
+    String::from_utf8(plaintext).map_err(|_| CryptoError(format!("{path}: not valid UTF-8")))
+}
+
+pub fn encrypt_file(key: &Key, path: &str) -> Result<Migrated, CryptoError> {
+    let encrypted = encrypted_path(path);
+    let plaintext = match fs::read(path) {
+        Ok(plaintext) if plaintext.is_empty() => {
+            return Ok(Migrated::Skipped("input is empty".to_string()))
+        }
+        Ok(plaintext) => plaintext,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Migrated::Missing),
+        Err(e) => return Err(CryptoError(format!("{path}: {e}"))),
+    };
+    if Path::new(&encrypted).exists() {
+        return Ok(Migrated::Skipped(format!("{encrypted} already exists")));
+    }
+    fs::write(&encrypted, encrypt(key, &plaintext))
+        .map_err(|e| CryptoError(format!("{encrypted}: {e}")))?;
+    fs::remove_file(path).map_err(|e| CryptoError(format!("{path}: {e}")))?;
+    Ok(Migrated::Done)
+}
+
+pub fn decrypt_file(key: &Key, path: &str) -> Result<Migrated, CryptoError> {
+    let encrypted = encrypted_path(path);
+    let blob = match fs::read(&encrypted) {
+        Ok(blob) => blob,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Migrated::Missing),
+        Err(e) => return Err(CryptoError(format!("{encrypted}: {e}"))),
+    };
+    if fs::metadata(path).is_ok_and(|m| m.len() > 0) {
+        return Ok(Migrated::Skipped(format!("{path} already exists")));
+    }
+    let plaintext = decrypt(key, &blob).map_err(|e| CryptoError(format!("{encrypted}: {e}")))?;
+    fs::write(path, plaintext).map_err(|e| CryptoError(format!("{path}: {e}")))?;
+    fs::remove_file(&encrypted).map_err(|e| CryptoError(format!("{encrypted}: {e}")))?;
+    Ok(Migrated::Done)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crypto::{
+        decrypt, decrypt_file, encrypt, encrypt_file, encrypted_path, Key, Migrated,
+    };
+    use std::fs;
+
+    const HEX: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
+
+    #[test]
+    fn test_parse_key() {
+        assert!(Key::parse(HEX).is_ok());
+        assert!(Key::parse(&format!("  {HEX}\n")).is_ok());
+        assert!(Key::parse(&HEX[2..]).is_err());
+        assert!(Key::parse(&HEX.replace('0', "g")).is_err());
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let key = Key::parse(HEX).unwrap();
+        let blob = encrypt(&key, b"L68\nL30\n");
+        assert!(!blob.windows(3).any(|w| w == b"L68"));
+        assert_ne!(blob, encrypt(&key, b"L68\nL30\n"));
+        assert_eq!(decrypt(&key, &blob).unwrap(), b"L68\nL30\n");
+
+        let other = Key::parse(&HEX.replace('1', "2")).unwrap();
+        assert!(decrypt(&other, &blob).is_err());
+        assert!(decrypt(&key, b"L68\nL30\n").is_err());
+        let mut tampered = blob.clone();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(decrypt(&key, &tampered).is_err());
+    }
+
+    #[test]
+    fn test_migrate_files() {
+        let key = Key::parse(HEX).unwrap();
+        let dir = std::env::temp_dir().join(format!("aoc-crypto-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("input.txt").display().to_string();
+        fs::write(&path, "1-2,3-4\n").unwrap();
+
+        assert_eq!(encrypt_file(&key, &path), Ok(Migrated::Done));
+        assert!(!fs::exists(&path).unwrap());
+        assert_eq!(encrypt_file(&key, &path), Ok(Migrated::Missing));
+        assert_eq!(decrypt_file(&key, &path), Ok(Migrated::Done));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1-2,3-4\n");
+        assert!(!fs::exists(encrypted_path(&path)).unwrap());
+
+        fs::write(&path, "").unwrap();
+        assert!(matches!(
+            encrypt_file(&key, &path),
+            Ok(Migrated::Skipped(_))
+        ));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
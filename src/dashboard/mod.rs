@@ -0,0 +1,661 @@
+use crate::answers::AnswerStore;
+use crate::history::Run;
+use crate::report::split_csv;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEventKind, KeyModifiers};
+use crossterm::style::Print;
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use std::collections::{BTreeMap, VecDeque};
+use std::env;
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+const POLL_INTERVAL: Duration = Duration::from_millis(100);
+const HELP: &str =
+    "up/down select | enter run | 1/2 run part | a run all | s sample/real | o output | q quit";
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PartResult {
+    pub answer: String,
+    pub verdict: String,
+    pub duration: Option<Duration>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RunResult {
+    pub sample: bool,
+    pub status: String,
+    pub message: String,
+    pub parts: BTreeMap<u8, PartResult>,
+    pub stdout: Vec<String>,
+    pub stderr: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Row {
+    pub day: usize,
+    pub title: String,
+    pub recorded: [Option<String>; 2],
+    pub timings: [Option<Duration>; 2],
+    pub result: Option<RunResult>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Job {
+    pub day: usize,
+    pub part: Option<u8>,
+    pub sample: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Key {
+    Up,
+    Down,
+    Enter,
+    Esc,
+    Char(char),
+}
+
+pub struct Dashboard {
+    year: usize,
+    rows: Vec<Row>,
+    selected: usize,
+    sample: bool,
+    output: bool,
+    running: Option<Job>,
+    queue: VecDeque<Job>,
+    quit: bool,
+}
+
+impl Dashboard {
+    pub fn new(year: usize, rows: Vec<Row>) -> Self {
+        Dashboard {
+            year,
+            rows,
+            selected: 0,
+            sample: false,
+            output: false,
+            running: None,
+            queue: VecDeque::new(),
+            quit: false,
+        }
+    }
+
+    pub fn handle(&mut self, key: Key) {
+        match key {
+            Key::Up | Key::Char('k') => self.selected = self.selected.saturating_sub(1),
+            Key::Down | Key::Char('j') => {
+                self.selected = (self.selected + 1).min(self.rows.len().saturating_sub(1))
+            }
+            Key::Enter | Key::Char('r') => self.enqueue(self.selected, None),
+            Key::Char('1') => self.enqueue(self.selected, Some(1)),
+            Key::Char('2') => self.enqueue(self.selected, Some(2)),
+            Key::Char('a') => (0..self.rows.len()).for_each(|row| self.enqueue(row, None)),
+            Key::Char('s') => self.sample = !self.sample,
+            Key::Char('o') => self.output = !self.output,
+            Key::Esc if self.output => self.output = false,
+            Key::Char('q') | Key::Esc => self.quit = true,
+            Key::Char(_) => {}
+        }
+    }
+
+    fn enqueue(&mut self, row: usize, part: Option<u8>) {
+        let Some(row) = self.rows.get(row) else {
+            return;
+        };
+        let job = Job {
+            day: row.day,
+            part,
+            sample: self.sample,
+        };
+        if self.running != Some(job) && !self.queue.contains(&job) {
+            self.queue.push_back(job);
+        }
+    }
+
+    pub fn next_job(&mut self) -> Option<Job> {
+        if self.running.is_none() {
+            self.running = self.queue.pop_front();
+            return self.running;
+        }
+        None
+    }
+
+    pub fn finish(&mut self, job: Job, mut result: RunResult) {
+        self.running = None;
+        let Some(row) = self.rows.iter_mut().find(|row| row.day == job.day) else {
+            return;
+        };
+        if let (Some(part), Some(previous)) = (job.part, &row.result) {
+            if previous.sample == result.sample {
+                previous
+                    .parts
+                    .iter()
+                    .filter(|(p, _)| **p != part)
+                    .for_each(|(p, r)| {
+                        result.parts.insert(*p, r.clone());
+                    });
+            }
+        }
+        row.result = Some(result);
+    }
+
+    pub fn lines(&self, width: usize, height: usize) -> Vec<String> {
+        let lines = if self.output {
+            self.output_lines(height)
+        } else {
+            self.table_lines(height)
+        };
+        lines
+            .into_iter()
+            .take(height)
+            .map(|line| line.chars().take(width).collect())
+            .collect()
+    }
+
+    fn header(&self) -> String {
+        let input = if self.sample { "sample" } else { "real" };
+        let activity = match self.running {
+            Some(job) => format!(" | running Day {:02}", job.day),
+            None => String::new(),
+        };
+        let queued = match self.queue.len() {
+            0 => String::new(),
+            count => format!(" ({count} queued)"),
+        };
+        format!(
+            "Advent of Code {} | {input} input{activity}{queued}",
+            self.year
+        )
+    }
+
+    fn table_lines(&self, height: usize) -> Vec<String> {
+        let mut lines = vec![
+            self.header(),
+            String::new(),
+            format!(
+                "  {:<4} {:<28} {:<9} {:<20} {:<20} {:>10} {:>10}",
+                "Day", "Title", "Status", "Part 1", "Part 2", "Time 1", "Time 2"
+            ),
+        ];
+        let visible = height.saturating_sub(lines.len() + 2).max(1);
+        let start = (self.selected + 1).saturating_sub(visible);
+        for (i, row) in self.rows.iter().enumerate().skip(start).take(visible) {
+            let marker = if i == self.selected { '>' } else { ' ' };
+            let answer = |part: u8| {
+                let answer = match &row.result {
+                    Some(result) => result.parts.get(&part).map(|p| p.answer.clone()),
+                    None => row.recorded[part as usize - 1].clone(),
+                };
+                truncate(&answer.unwrap_or_default(), 20)
+            };
+            let timing = |part: u8| {
+                let duration = match &row.result {
+                    Some(result) => result.parts.get(&part).and_then(|p| p.duration),
+                    None => row.timings[part as usize - 1],
+                };
+                duration.map(|d| format!("{d:.1?}")).unwrap_or_default()
+            };
+            lines.push(format!(
+                "{marker} {:<4} {:<28} {:<9} {:<20} {:<20} {:>10} {:>10}",
+                format!("{:02}", row.day),
+                truncate(&row.title, 28),
+                self.status(row),
+                answer(1),
+                answer(2),
+                timing(1),
+                timing(2)
+            ));
+        }
+        while lines.len() < height.saturating_sub(1) {
+            lines.push(String::new());
+        }
+        lines.push(HELP.to_string());
+        lines
+    }
+
+    fn status(&self, row: &Row) -> String {
+        if self.running.is_some_and(|job| job.day == row.day) {
+            return "running".to_string();
+        }
+        if self.queue.iter().any(|job| job.day == row.day) {
+            return "queued".to_string();
+        }
+        let Some(result) = &row.result else {
+            return "-".to_string();
+        };
+        let verdicts = result
+            .parts
+            .values()
+            .map(|p| p.verdict.as_str())
+            .collect::<Vec<&str>>();
+        if result.status != "ok" {
+            result.status.clone()
+        } else if verdicts.contains(&"fail") {
+            "fail".to_string()
+        } else if !verdicts.is_empty() && verdicts.iter().all(|v| *v == "pass") {
+            "pass".to_string()
+        } else {
+            "ok".to_string()
+        }
+    }
+
+    fn output_lines(&self, height: usize) -> Vec<String> {
+        let Some(row) = self.rows.get(self.selected) else {
+            return vec![self.header()];
+        };
+        let mut lines = Vec::new();
+        match &row.result {
+            None => lines.push(format!(
+                "Day {:02} - {} has not been run yet",
+                row.day, row.title
+            )),
+            Some(result) => {
+                let input = if result.sample { "sample" } else { "real" };
+                lines.push(format!(
+                    "Day {:02} - {} | {input} input | {}",
+                    row.day,
+                    row.title,
+                    self.status(row)
+                ));
+                if !result.message.is_empty() {
+                    lines.push(result.message.clone());
+                }
+                for (name, captured) in [("stdout", &result.stdout), ("stderr", &result.stderr)] {
+                    lines.push(String::new());
+                    if captured.is_empty() {
+                        lines.push(format!("{name}: (empty)"));
+                    } else {
+                        lines.push(format!("{name}:"));
+                        lines.extend(captured.iter().map(|line| format!("  {line}")));
+                    }
+                }
+            }
+        }
+        let room = height.saturating_sub(2);
+        if lines.len() > room {
+            let kept = room.saturating_sub(1);
+            let hidden = lines.len().saturating_sub(kept);
+            lines.truncate(kept);
+            lines.push(format!("... {hidden} more lines"));
+        }
+        while lines.len() < height.saturating_sub(1) {
+            lines.push(String::new());
+        }
+        lines.push("o/esc back | q quit".to_string());
+        lines
+    }
+}
+
+pub fn rows(year: usize, days: &[(usize, &str)], answers: &AnswerStore, runs: &[Run]) -> Vec<Row> {
+    let timing = |day: usize, phase: &str| {
+        runs.iter()
+            .rev()
+            .flat_map(|run| run.entries.iter())
+            .find(|e| e.year == year && e.day == day && e.phase == phase)
+            .map(|e| e.duration)
+    };
+    days.iter()
+        .map(|(day, title)| {
+            let expected = answers.expected(year, *day);
+            Row {
+                day: *day,
+                title: title.to_string(),
+                recorded: [expected.easy, expected.hard],
+                timings: [timing(*day, "easy"), timing(*day, "hard")],
+                result: None,
+            }
+        })
+        .collect()
+}
+
+pub fn run(mut dashboard: Dashboard) -> io::Result<()> {
+    let _terminal = Terminal::enter()?;
+    let mut out = io::stdout();
+    let mut pending: Option<(Job, Receiver<RunResult>)> = None;
+    let mut dirty = true;
+    while !dashboard.quit {
+        if pending.is_none() {
+            if let Some(job) = dashboard.next_job() {
+                let (tx, rx) = mpsc::channel();
+                let year = dashboard.year;
+                thread::spawn(move || {
+                    let _ = tx.send(execute(year, job));
+                });
+                pending = Some((job, rx));
+                dirty = true;
+            }
+        }
+        if let Some((job, rx)) = &pending {
+            if let Ok(result) = rx.try_recv() {
+                dashboard.finish(*job, result);
+                pending = None;
+                dirty = true;
+                continue;
+            }
+        }
+        if dirty {
+            let (width, height) = terminal::size()?;
+            draw(&mut out, &dashboard.lines(width as usize, height as usize))?;
+            dirty = false;
+        }
+        if !event::poll(POLL_INTERVAL)? {
+            continue;
+        }
+        match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => {
+                let key = match key.code {
+                    KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        Key::Char('q')
+                    }
+                    KeyCode::Up => Key::Up,
+                    KeyCode::Down => Key::Down,
+                    KeyCode::Enter => Key::Enter,
+                    KeyCode::Esc => Key::Esc,
+                    KeyCode::Char(c) => Key::Char(c),
+                    _ => continue,
+                };
+                dashboard.handle(key);
+                dirty = true;
+            }
+            Event::Resize(_, _) => dirty = true,
+            _ => {}
+        }
+    }
+    Ok(())
+}
+
+struct Terminal;
+
+impl Terminal {
+    fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, Hide)?;
+        Ok(Terminal)
+    }
+}
+
+impl Drop for Terminal {
+    fn drop(&mut self) {
+        let _ = execute!(io::stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn draw(out: &mut impl Write, lines: &[String]) -> io::Result<()> {
+    queue!(out, Clear(ClearType::All))?;
+    for (i, line) in lines.iter().enumerate() {
+        queue!(out, MoveTo(0, i as u16), Print(line))?;
+    }
+    out.flush()
+}
+
+fn execute(year: usize, job: Job) -> RunResult {
+    let exe = env::current_exe().unwrap_or_else(|_| "all".into());
+    let mut command = Command::new(exe);
+    command
+        .arg(job.day.to_string())
+        .args(["--year", &year.to_string(), "--format", "csv"]);
+    if job.sample {
+        command.arg("--sample");
+    }
+    if let Some(part) = job.part {
+        command.args(["--part", &part.to_string()]);
+    }
+    match command.stdin(Stdio::null()).output() {
+        Ok(output) => parse_output(
+            job,
+            &String::from_utf8_lossy(&output.stdout),
+            &String::from_utf8_lossy(&output.stderr),
+        ),
+        Err(e) => RunResult {
+            sample: job.sample,
+            status: "failed".to_string(),
+            message: format!("Could not run the day: {e}"),
+            ..RunResult::default()
+        },
+    }
+}
+
+fn parse_output(job: Job, stdout: &str, stderr: &str) -> RunResult {
+    let mut result = RunResult {
+        sample: job.sample,
+        stderr: stderr.lines().map(|line| line.to_string()).collect(),
+        ..RunResult::default()
+    };
+    let lines = stdout.lines().collect::<Vec<&str>>();
+    let header = lines
+        .iter()
+        .position(|line| line.starts_with("day,title,status,"));
+    let fields = header.map(|h| split_csv(lines[h])).unwrap_or_default();
+    let record = header.and_then(|h| {
+        (h + 1..lines.len()).rev().find(|i| {
+            let record = split_csv(lines[*i]);
+            record.len() == fields.len() && record[0] == job.day.to_string()
+        })
+    });
+    result.stdout = lines
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| Some(*i) != header && Some(*i) != record)
+        .map(|(_, line)| line.to_string())
+        .collect();
+    let Some(record) = record.map(|i| split_csv(lines[i])) else {
+        result.status = "failed".to_string();
+        result.message = result
+            .stderr
+            .last()
+            .cloned()
+            .unwrap_or_else(|| "The day produced no results".to_string());
+        return result;
+    };
+    let field = |name: &str| {
+        fields
+            .iter()
+            .position(|f| f == name)
+            .and_then(|i| record.get(i))
+            .cloned()
+            .unwrap_or_default()
+    };
+    result.status = field("status");
+    result.message = field("message");
+    for part in 1..=2 {
+        let duration = field(&format!("part{part}_duration_ns"));
+        if duration.is_empty() {
+            continue;
+        }
+        let duration = duration.parse::<u64>().ok().map(Duration::from_nanos);
+        let answer = field(&format!("part{part}_answer"));
+        result.parts.insert(
+            part,
+            PartResult {
+                answer: if answer.is_empty() {
+                    "No Result".to_string()
+                } else {
+                    answer
+                },
+                verdict: field(&format!("part{part}_verdict")),
+                duration,
+            },
+        );
+    }
+    result
+}
+
+fn truncate(value: &str, width: usize) -> String {
+    if value.chars().count() > width {
+        format!("{}~", value.chars().take(width - 1).collect::<String>())
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::dashboard::{parse_output, Dashboard, Job, Key, PartResult, Row, RunResult};
+    use std::time::Duration;
+
+    fn dashboard() -> Dashboard {
+        let row = |day: usize, title: &str| Row {
+            day,
+            title: title.to_string(),
+            recorded: [Some("1040".to_string()), None],
+            timings: [Some(Duration::from_micros(35)), None],
+            result: None,
+        };
+        Dashboard::new(
+            2025,
+            vec![
+                row(1, "Secret Entrance"),
+                row(2, "Gift Shop"),
+                row(7, "Laboratories"),
+            ],
+        )
+    }
+
+    fn result(sample: bool, parts: &[(u8, &str, &str)]) -> RunResult {
+        RunResult {
+            sample,
+            status: "ok".to_string(),
+            parts: parts
+                .iter()
+                .map(|(part, answer, verdict)| {
+                    let result = PartResult {
+                        answer: answer.to_string(),
+                        verdict: verdict.to_string(),
+                        duration: Some(Duration::from_micros(*part as u64)),
+                    };
+                    (*part, result)
+                })
+                .collect(),
+            ..RunResult::default()
+        }
+    }
+
+    #[test]
+    fn test_keys_queue_jobs() {
+        let mut dashboard = dashboard();
+        dashboard.handle(Key::Down);
+        dashboard.handle(Key::Enter);
+        dashboard.handle(Key::Enter);
+        dashboard.handle(Key::Char('s'));
+        dashboard.handle(Key::Char('2'));
+        let job = |day, part, sample| Job { day, part, sample };
+        assert_eq!(dashboard.next_job(), Some(job(2, None, false)));
+        assert_eq!(dashboard.next_job(), None);
+        dashboard.finish(job(2, None, false), result(false, &[(1, "a", "")]));
+        assert_eq!(dashboard.next_job(), Some(job(2, Some(2), true)));
+
+        dashboard.handle(Key::Char('a'));
+        assert_eq!(dashboard.queue.len(), 3);
+        dashboard.handle(Key::Down);
+        dashboard.handle(Key::Down);
+        assert_eq!(dashboard.selected, 2);
+        dashboard.handle(Key::Up);
+        assert_eq!(dashboard.selected, 1);
+        dashboard.handle(Key::Char('o'));
+        dashboard.handle(Key::Esc);
+        assert!(!dashboard.quit);
+        dashboard.handle(Key::Char('q'));
+        assert!(dashboard.quit);
+    }
+
+    #[test]
+    fn test_part_results_are_merged() {
+        let mut dashboard = dashboard();
+        let job = |part| Job {
+            day: 1,
+            part,
+            sample: false,
+        };
+        dashboard.finish(
+            job(None),
+            result(false, &[(1, "3", "pass"), (2, "5", "fail")]),
+        );
+        dashboard.finish(job(Some(2)), result(false, &[(2, "6", "pass")]));
+        let parts = &dashboard.rows[0].result.as_ref().unwrap().parts;
+        assert_eq!(parts[&1].answer, "3");
+        assert_eq!(parts[&2].answer, "6");
+        dashboard.finish(job(Some(2)), result(true, &[(2, "7", "")]));
+        assert_eq!(dashboard.rows[0].result.as_ref().unwrap().parts.len(), 1);
+    }
+
+    #[test]
+    fn test_table_lines() {
+        let mut dashboard = dashboard();
+        dashboard.finish(
+            Job {
+                day: 2,
+                part: None,
+                sample: false,
+            },
+            result(
+                false,
+                &[(1, "1227775554", "pass"), (2, "4174379265", "pass")],
+            ),
+        );
+        dashboard.handle(Key::Char('a'));
+        dashboard.next_job();
+        let lines = dashboard.lines(120, 10);
+        assert_eq!(lines.len(), 10);
+        assert_eq!(
+            lines[0],
+            "Advent of Code 2025 | real input | running Day 01 (2 queued)"
+        );
+        assert!(lines[3].starts_with("> 01   Secret Entrance              running   1040"));
+        assert!(lines[3].ends_with("35.0µs           "));
+        assert!(lines[4].starts_with("  02   Gift Shop                    queued    1227775554"));
+        assert!(lines[4].contains("1.0µs      2.0µs"));
+        assert!(lines[9].starts_with("up/down select"));
+        assert!(dashboard
+            .lines(20, 3)
+            .iter()
+            .all(|l| l.chars().count() <= 20));
+    }
+
+    #[test]
+    fn test_output_lines() {
+        let mut dashboard = dashboard();
+        let job = Job {
+            day: 7,
+            part: None,
+            sample: true,
+        };
+        let stdout = "day,title,status,message,load_ns,parse_ns,part1_duration_ns,part1_answer,\
+part1_verdict,part2_duration_ns,part2_answer,part2_verdict\n\
+..^..\n.^.^.\n\
+7,Day 07 - Laboratories,ok,,5,10,2000,21,pass,3000,40,fail\n";
+        let result = parse_output(job, stdout, "warning: slow\n");
+        assert_eq!(result.stdout, vec!["..^..", ".^.^."]);
+        assert_eq!(result.parts[&1].answer, "21");
+        assert_eq!(result.parts[&2].duration, Some(Duration::from_micros(3)));
+        dashboard.finish(job, result);
+        dashboard.handle(Key::Char('j'));
+        dashboard.handle(Key::Char('j'));
+        dashboard.handle(Key::Char('o'));
+        let lines = dashboard.lines(80, 12);
+        assert_eq!(
+            lines[..7],
+            [
+                "Day 07 - Laboratories | sample input | fail",
+                "",
+                "stdout:",
+                "  ..^..",
+                "  .^.^.",
+                "",
+                "stderr:"
+            ]
+        );
+        assert_eq!(dashboard.lines(80, 6)[3], "... 5 more lines");
+        assert_eq!(dashboard.lines(80, 2)[0], "... 8 more lines");
+        assert!(dashboard.lines(80, 0).is_empty());
+
+        let failed = parse_output(job, "", "error: Input file missing\n");
+        assert_eq!(failed.status, "failed");
+        assert_eq!(failed.message, "error: Input file missing");
+    }
+}
@@ -1,21 +1,119 @@
+use crate::config::Config;
+use crate::crypto::{self, CryptoError, Key};
+use crate::parse::ParseError;
 use std::cell::RefCell;
-use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, OnceLock};
 use std::time::{Duration, Instant};
 
-pub const PREFIX: &str = "./src/challenges/day";
+macro_rules! root_path {
+    ($path:literal) => {
+        concat!(env!("CARGO_MANIFEST_DIR"), $path)
+    };
+}
+
+pub(crate) use root_path;
+
+pub const ROOT: &str = root_path!("");
+pub const CHALLENGES: &str = root_path!("/src/challenges");
+
+pub fn day_dir(year: usize, day: usize) -> String {
+    format!("{CHALLENGES}/year_{year}/day_{day:02}")
+}
+
+pub fn input_path(year: usize, day: usize) -> String {
+    match &Config::get().input_dir {
+        Some(dir) => format!("{}/{year}/day_{day:02}.txt", dir.trim_end_matches('/')),
+        None => format!("{}/input.txt", day_dir(year, day)),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Input {
+    Default,
+    Sample,
+    File(String),
+    Stdin,
+}
+
+impl Input {
+    pub fn load(&self, year: usize, day: usize) -> Result<Source, ParseError> {
+        match self {
+            Input::Default => Source::from_file(&input_path(year, day)),
+            Input::Sample => Source::from_file(&format!("{}/sample.txt", day_dir(year, day))),
+            Input::File(path) => Source::from_file(path),
+            Input::Stdin => Source::from_stdin(),
+        }
+    }
+}
+
+pub struct Source {
+    pub path: String,
+    pub text: String,
+}
+
+impl Source {
+    pub fn from_file(path: &str) -> Result<Self, ParseError> {
+        Self::read(path, Key::load)
+    }
+
+    fn read(path: &str, key: fn() -> Result<Key, CryptoError>) -> Result<Self, ParseError> {
+        let encrypted = crypto::encrypted_path(path);
+        let encrypted = if path.ends_with(".enc") {
+            Some(path)
+        } else if !Path::new(path).exists() && Path::new(&encrypted).exists() {
+            Some(encrypted.as_str())
+        } else {
+            None
+        };
+        let text = match encrypted {
+            Some(encrypted) => key()
+                .and_then(|key| crypto::read_encrypted(&key, encrypted))
+                .map_err(|e| ParseError::read(encrypted, e.to_string()))?,
+            None => fs::read_to_string(path).map_err(|e| ParseError::read(path, e.to_string()))?,
+        };
+        Ok(Source {
+            path: path.to_string(),
+            text,
+        })
+    }
+
+    pub fn from_stdin() -> Result<Self, ParseError> {
+        static STDIN: OnceLock<Result<String, String>> = OnceLock::new();
+        let text = STDIN
+            .get_or_init(|| io::read_to_string(io::stdin()).map_err(|e| e.to_string()))
+            .clone()
+            .map_err(|e| ParseError::read("<stdin>", e))?;
+        Ok(Source {
+            path: "<stdin>".to_string(),
+            text,
+        })
+    }
+}
 
 pub struct Reader {
     iter: RefCell<Box<dyn Iterator<Item = String>>>,
+    line: usize,
 }
 
 impl Reader {
-    pub fn from_file(path: &str) -> Self {
-        let file = File::open(path).unwrap();
-        let reader = BufReader::new(file.try_clone().unwrap());
-        let iter = reader.lines().map(|l| l.unwrap());
-        let iter = RefCell::new(Box::new(iter));
-        Self { iter }
+    pub fn from_text(text: &str) -> Self {
+        Self::from_lines(text.lines().map(|l| l.to_owned()).collect())
+    }
+
+    fn from_lines(lines: Vec<String>) -> Self {
+        let iter = RefCell::new(Box::new(lines.into_iter()) as Box<dyn Iterator<Item = String>>);
+        Self { iter, line: 0 }
+    }
+
+    pub fn numbered(&mut self) -> impl Iterator<Item = (usize, String)> + '_ {
+        std::iter::from_fn(|| {
+            let line = self.next()?;
+            Some((self.line, line))
+        })
     }
 }
 
@@ -23,7 +121,28 @@ impl Iterator for Reader {
     type Item = String;
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.iter.borrow_mut().next()
+        let line = self.iter.borrow_mut().next()?;
+        self.line += 1;
+        Some(line)
+    }
+}
+
+#[derive(Clone, Default)]
+pub struct Cancellation {
+    cancelled: Arc<AtomicBool>,
+}
+
+impl Cancellation {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::Relaxed)
     }
 }
 
@@ -39,26 +158,53 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::helpers::Reader;
-    use std::cell::RefCell;
+    use crate::crypto::{encrypt_file, Key};
+    use crate::helpers::{Reader, Source};
+    use std::fs;
+
+    const HEX: &str = "000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f";
 
     impl Reader {
         pub fn from_vec(lines: Vec<&str>) -> Self {
-            Self {
-                iter: RefCell::new(Box::new(
-                    lines
-                        .into_iter()
-                        .map(|x| x.to_owned())
-                        .collect::<Vec<String>>()
-                        .into_iter(),
-                )),
-            }
+            Self::from_lines(lines.into_iter().map(|x| x.to_owned()).collect())
         }
 
         pub fn single(line: &str) -> Self {
-            Self {
-                iter: RefCell::new(Box::new(vec![line.to_owned()].into_iter())),
-            }
+            Self::from_lines(vec![line.to_owned()])
         }
     }
+
+    #[test]
+    fn test_reader_numbers_lines() {
+        let mut reader = Reader::from_vec(vec!["a", "", "b"]);
+        assert_eq!(reader.next(), Some("a".to_string()));
+        let rest = reader.numbered().collect::<Vec<_>>();
+        assert_eq!(rest, vec![(2, String::new()), (3, "b".to_string())]);
+        let reader = Reader::from_text("L68\r\nL30\n");
+        assert_eq!(reader.collect::<Vec<_>>(), vec!["L68", "L30"]);
+        let error = Source::from_file("/nonexistent/input.txt").err().unwrap();
+        assert_eq!(error.path, "/nonexistent/input.txt");
+        assert_eq!(error.line, 0);
+    }
+
+    #[test]
+    fn test_reads_encrypted_only_checkout() {
+        let dir = std::env::temp_dir().join(format!("aoc-helpers-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("input.txt").display().to_string();
+        fs::write(&path, "L68\nL30\n").unwrap();
+        encrypt_file(&Key::parse(HEX).unwrap(), &path).unwrap();
+        assert!(!fs::exists(&path).unwrap());
+
+        let source = Source::read(&path, || Key::parse(HEX)).unwrap();
+        assert_eq!(
+            (source.path.as_str(), source.text.as_str()),
+            (path.as_str(), "L68\nL30\n")
+        );
+        let other = || Key::parse(&HEX.replace('1', "2"));
+        let error = Source::read(&path, other).err().unwrap();
+        assert_eq!(error.path, format!("{path}.enc"));
+        fs::remove_dir_all(&dir).unwrap();
+    }
 }
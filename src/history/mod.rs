@@ -0,0 +1,412 @@
+use crate::helpers::{root_path, ROOT};
+use crate::report::{DayReport, Status};
+use std::fs;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+use std::process::Command;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const HISTORY_PATH: &str = root_path!("/.aoc/history.csv");
+
+const HEADER: &str = "timestamp,commit,year,day,phase,duration_ns,iterations";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Entry {
+    pub timestamp: String,
+    pub commit: String,
+    pub year: usize,
+    pub day: usize,
+    pub phase: String,
+    pub duration: Duration,
+    pub iterations: usize,
+}
+
+impl Entry {
+    fn same_phase(&self, other: &Entry) -> bool {
+        self.year == other.year && self.day == other.day && self.phase == other.phase
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Run {
+    pub timestamp: String,
+    pub commit: String,
+    pub entries: Vec<Entry>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Change {
+    pub year: usize,
+    pub day: usize,
+    pub phase: String,
+    pub baseline: Duration,
+    pub latest: Duration,
+}
+
+impl Change {
+    pub fn percent(&self) -> f64 {
+        let baseline = self.baseline.as_nanos().max(1) as f64;
+        (self.latest.as_nanos() as f64 - baseline) / baseline * 100.0
+    }
+
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.percent() > threshold
+    }
+}
+
+pub fn entries(report: &DayReport, year: usize, timestamp: &str, commit: &str) -> Vec<Entry> {
+    let iterations = report
+        .parse_stats
+        .as_ref()
+        .map(|s| s.iterations)
+        .unwrap_or(1);
+    let entry = |phase: &str, duration: Duration| Entry {
+        timestamp: timestamp.to_string(),
+        commit: commit.to_string(),
+        year,
+        day: report.day,
+        phase: phase.to_string(),
+        duration,
+        iterations,
+    };
+    if matches!(report.status, Status::Panicked(_) | Status::Invalid(_)) {
+        return Vec::new();
+    }
+    let mut entries = vec![entry("load", report.load), entry("parse", report.parse)];
+    report.parts.iter().filter(|p| !p.timed_out).for_each(|p| {
+        let phase = if p.part == 1 { "easy" } else { "hard" };
+        entries.push(entry(phase, p.duration));
+    });
+    entries
+}
+
+pub fn append(path: &str, entries: &[Entry]) -> io::Result<()> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let exists = Path::new(path).exists();
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    if !exists {
+        writeln!(file, "{HEADER}")?;
+    }
+    for e in entries {
+        writeln!(
+            file,
+            "{},{},{},{},{},{},{}",
+            e.timestamp,
+            e.commit,
+            e.year,
+            e.day,
+            e.phase,
+            e.duration.as_nanos(),
+            e.iterations
+        )?;
+    }
+    Ok(())
+}
+
+pub fn load(path: &str) -> io::Result<Vec<Run>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(parse(&contents)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+fn parse(contents: &str) -> Vec<Run> {
+    let mut runs: Vec<Run> = Vec::new();
+    for line in contents.lines().filter(|l| !l.is_empty() && *l != HEADER) {
+        let fields = line.split(',').collect::<Vec<&str>>();
+        let [timestamp, commit, year, day, phase, duration, iterations] = fields[..] else {
+            continue;
+        };
+        let (Ok(year), Ok(day), Ok(duration), Ok(iterations)) = (
+            year.parse::<usize>(),
+            day.parse::<usize>(),
+            duration.parse::<u64>(),
+            iterations.parse::<usize>(),
+        ) else {
+            continue;
+        };
+        let entry = Entry {
+            timestamp: timestamp.to_string(),
+            commit: commit.to_string(),
+            year,
+            day,
+            phase: phase.to_string(),
+            duration: Duration::from_nanos(duration),
+            iterations,
+        };
+        match runs.last_mut() {
+            Some(run) if run.timestamp == timestamp && run.commit == commit => {
+                run.entries.push(entry)
+            }
+            _ => runs.push(Run {
+                timestamp: timestamp.to_string(),
+                commit: commit.to_string(),
+                entries: vec![entry],
+            }),
+        }
+    }
+    runs
+}
+
+pub fn select<'a>(runs: &'a [Run], baseline: Option<&str>) -> Option<(&'a Run, &'a Run)> {
+    let (latest, earlier) = runs.split_last()?;
+    let baseline = match baseline {
+        Some(reference) => earlier
+            .iter()
+            .rev()
+            .find(|r| r.commit.starts_with(reference) || r.timestamp == reference)?,
+        None => earlier.iter().rev().find(|r| {
+            r.entries
+                .iter()
+                .any(|b| latest.entries.iter().any(|e| e.same_phase(b)))
+        })?,
+    };
+    Some((baseline, latest))
+}
+
+pub fn compare(baseline: &Run, latest: &Run) -> Vec<Change> {
+    latest
+        .entries
+        .iter()
+        .filter_map(|entry| {
+            let before = baseline.entries.iter().find(|b| b.same_phase(entry))?;
+            Some(Change {
+                year: entry.year,
+                day: entry.day,
+                phase: entry.phase.clone(),
+                baseline: before.duration,
+                latest: entry.duration,
+            })
+        })
+        .collect()
+}
+
+pub fn comparison_lines(
+    baseline: &Run,
+    latest: &Run,
+    changes: &[Change],
+    threshold: f64,
+) -> Vec<String> {
+    let mut lines = vec![
+        format!("|| Baseline: {} ({})", baseline.commit, baseline.timestamp),
+        format!("|| Latest:   {} ({})", latest.commit, latest.timestamp),
+        "||".to_string(),
+    ];
+    if changes.is_empty() {
+        lines.push("|| No phases in common".to_string());
+    }
+    for change in changes {
+        let flag = if change.is_regression(threshold) {
+            "  REGRESSION"
+        } else {
+            ""
+        };
+        lines.push(format!(
+            "|| {} Day {:02} {:<12} {:>12} -> {:<12} {:>+7.1}%{flag}",
+            change.year,
+            change.day,
+            change.phase,
+            format!("{:?}", change.baseline),
+            format!("{:?}", change.latest),
+            change.percent()
+        ));
+    }
+    lines
+}
+
+pub fn current_commit() -> String {
+    let git = |args: &[&str]| {
+        Command::new("git")
+            .args(args)
+            .current_dir(ROOT)
+            .output()
+            .ok()
+            .filter(|output| output.status.success())
+            .map(|output| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    match git(&["rev-parse", "--short", "HEAD"]) {
+        Some(hash) => match git(&["status", "--porcelain", "--untracked-files=no"]) {
+            Some(status) if !status.is_empty() => format!("{hash}-dirty"),
+            _ => hash,
+        },
+        None => "unknown".to_string(),
+    }
+}
+
+pub fn timestamp() -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default();
+    format_timestamp(now)
+}
+
+fn format_timestamp(since_epoch: Duration) -> String {
+    let seconds = since_epoch.as_secs();
+    let (days, time) = (seconds / 86400, seconds % 86400);
+    // Civil date from days since the epoch, after Howard Hinnant's `civil_from_days`
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}.{:03}Z",
+        time / 3600,
+        time % 3600 / 60,
+        time % 60,
+        since_epoch.subsec_millis()
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::challenges::Solution;
+    use crate::history::{compare, entries, format_timestamp, parse, select, Change, HEADER};
+    use crate::report::{DayReport, PartReport, Status};
+    use std::time::Duration;
+
+    fn history() -> String {
+        [
+            HEADER,
+            "2025-12-01T10:00:00.000Z,aaa1111,2025,1,parse,1000,1",
+            "2025-12-01T10:00:00.000Z,aaa1111,2025,1,easy,2000,1",
+            "2025-12-02T10:00:00.000Z,bbb2222,2025,1,easy,2100,1",
+            "2025-12-03T10:00:00.000Z,ccc3333,2025,1,parse,1000,1",
+            "2025-12-03T10:00:00.000Z,ccc3333,2025,1,easy,3000,1",
+            "2025-12-03T10:00:00.000Z,ccc3333,2025,2,easy,500,1",
+            "2025-12-03T10:00:00.000Z,ccc3333,2024,1,easy,500,1",
+        ]
+        .join("\n")
+    }
+
+    #[test]
+    fn test_parse_groups_runs() {
+        let runs = parse(&history());
+        assert_eq!(runs.len(), 3);
+        assert_eq!(runs[0].commit, "aaa1111");
+        assert_eq!(runs[0].entries.len(), 2);
+        assert_eq!(runs[2].entries[1].duration, Duration::from_nanos(3000));
+        assert!(parse("garbage\n1,2,3").is_empty());
+    }
+
+    #[test]
+    fn test_select_and_compare() {
+        let runs = parse(&history());
+        let (baseline, latest) = select(&runs, None).unwrap();
+        assert_eq!(
+            (baseline.commit.as_str(), latest.commit.as_str()),
+            ("bbb2222", "ccc3333")
+        );
+        let (baseline, _) = select(&runs, Some("aaa")).unwrap();
+        assert_eq!(baseline.commit, "aaa1111");
+        assert!(select(&runs, Some("ccc")).is_none());
+        assert!(select(&runs[..1], None).is_none());
+
+        let changes = compare(baseline, latest);
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].phase, "parse");
+        assert_eq!(changes[0].percent(), 0.0);
+        assert_eq!(changes[1].percent(), 50.0);
+        assert!(changes[1].is_regression(10.0));
+        assert!(!changes[1].is_regression(50.0));
+    }
+
+    #[test]
+    fn test_default_baseline_shares_a_phase() {
+        let history = [
+            HEADER,
+            "2025-12-01T10:00:00.000Z,aaa1111,2025,3,easy,1000,1",
+            "2025-12-02T10:00:00.000Z,bbb2222,2025,5,easy,2000,1",
+            "2025-12-02T11:00:00.000Z,ccc3333,2024,3,easy,2000,1",
+            "2025-12-03T10:00:00.000Z,ddd4444,2025,3,easy,3000,1",
+        ]
+        .join("\n");
+        let runs = parse(&history);
+        let (baseline, latest) = select(&runs, None).unwrap();
+        assert_eq!(
+            (baseline.commit.as_str(), latest.commit.as_str()),
+            ("aaa1111", "ddd4444")
+        );
+        assert!(select(&runs[1..], None).is_none());
+    }
+
+    #[test]
+    fn test_improvement_is_not_a_regression() {
+        let change = Change {
+            year: 2025,
+            day: 1,
+            phase: "easy".to_string(),
+            baseline: Duration::from_micros(10),
+            latest: Duration::from_micros(5),
+        };
+        assert_eq!(change.percent(), -50.0);
+        assert!(!change.is_regression(0.0));
+    }
+
+    #[test]
+    fn test_entries_skip_timed_out_parts() {
+        let part = |part: u8, timed_out: bool| PartReport {
+            part,
+            duration: Duration::from_micros(part as u64),
+            answer: Solution::Unsolved,
+            verdict: None,
+            stats: None,
+            timed_out,
+        };
+        let report = DayReport {
+            day: 4,
+            title: "Day 04 - Printing Department".to_string(),
+            status: Status::Ok,
+            elapsed: Duration::ZERO,
+            load: Duration::from_micros(3),
+            load_stats: None,
+            parse: Duration::from_micros(7),
+            parse_stats: None,
+            parts: vec![part(1, false), part(2, true)],
+        };
+        let entries = entries(&report, 2025, "now", "abc");
+        let phases = entries.iter().map(|e| e.phase.as_str()).collect::<Vec<_>>();
+        assert_eq!(phases, vec!["load", "parse", "easy"]);
+        assert_eq!(entries[1].duration, Duration::from_micros(7));
+    }
+
+    #[test]
+    fn test_entries_skip_failed_days() {
+        let report = DayReport {
+            day: 4,
+            title: "Day 04".to_string(),
+            status: Status::Panicked("boom".to_string()),
+            elapsed: Duration::ZERO,
+            load: Duration::ZERO,
+            load_stats: None,
+            parse: Duration::ZERO,
+            parse_stats: None,
+            parts: Vec::new(),
+        };
+        assert!(entries(&report, 2025, "now", "abc").is_empty());
+    }
+
+    #[test]
+    fn test_format_timestamp() {
+        assert_eq!(format_timestamp(Duration::ZERO), "1970-01-01T00:00:00.000Z");
+        assert_eq!(
+            format_timestamp(Duration::from_millis(1_764_582_245_123)),
+            "2025-12-01T09:44:05.123Z"
+        );
+        assert_eq!(
+            format_timestamp(Duration::from_secs(951_782_400)),
+            "2000-02-29T00:00:00.000Z"
+        );
+    }
+}
@@ -1,88 +1,73 @@
+mod answers;
+mod bench;
 mod challenges;
+mod cli;
+mod client;
+mod commands;
+mod config;
+mod crypto;
+mod dashboard;
 mod helpers;
+mod history;
+mod parse;
+mod puzzle;
+mod readme;
+mod report;
+mod runner;
+mod scaffold;
+mod submit;
+mod watch;
 
+use crate::cli::{Command, USAGE};
 use std::{env, process};
-use crate::challenges::day_01;
-use crate::challenges::day_02;
-use crate::challenges::day_03;
-use crate::challenges::day_04;
-use crate::challenges::day_05;
-use crate::challenges::day_06;
-use crate::challenges::day_07;
-use crate::challenges::day_08;
-use crate::challenges::day_09;
-use crate::challenges::day_10;
-use crate::challenges::day_11;
-use crate::challenges::day_12;
-use crate::challenges::Challenge;
-use crate::helpers::time_it;
 
 fn main() {
-    let mut challenges: Vec<fn() -> Box<dyn Challenge>> = Vec::new();
-    challenges.push(|| Box::new(day_01::State::new()));
-    challenges.push(|| Box::new(day_02::State::new()));
-    challenges.push(|| Box::new(day_03::State::new()));
-    challenges.push(|| Box::new(day_04::State::new()));
-    challenges.push(|| Box::new(day_05::State::new()));
-    challenges.push(|| Box::new(day_06::State::new()));
-    challenges.push(|| Box::new(day_07::State::new()));
-    challenges.push(|| Box::new(day_08::State::new()));
-    challenges.push(|| Box::new(day_09::State::new()));
-    challenges.push(|| Box::new(day_10::State::new()));
-    challenges.push(|| Box::new(day_11::State::new()));
-    challenges.push(|| Box::new(day_12::State::new()));
-
-    let arg = env::args().nth(1).or(Some("0".to_string())).unwrap();
-    let day = arg.parse::<usize>().unwrap();
-
-    match day {
-        0 => challenges.iter_mut().for_each(|ctor| {
-            run_and_print_challenge(ctor);
-        }),
-        _ => {
-            if day - 1 >= challenges.len() {
-                eprintln!("There are only {} challenges!", challenges.len());
-                process::exit(1);
-            }
-            let mut ctor = challenges[day - 1];
-            run_and_print_challenge(&mut ctor);
+    let registry = match challenges::registry() {
+        Ok(registry) => registry,
+        Err(e) => {
+            eprintln!("error: {e}");
+            process::exit(2);
         }
-    }
-}
-
-fn run_and_print_challenge(ctor: &mut fn() -> Box<dyn Challenge>) {
-    let (mut c, duration) = time_it(|| ctor());
-    let mut lines: Vec<String> = Vec::new();
-
-    lines.push(format!("|| {}", c.preamble()));
-    lines.push(format!("|| Construction: {:?}", duration));
-    lines.push("||".to_string());
+    };
 
-    let (res, duration) = time_it(|| c.run_easy());
-    lines.push(format!("|| Easy: {:?}", duration));
-    lines.push(format!("|| {res}"));
-    lines.push("||".to_string());
-
-    let (res, duration) = time_it(|| c.run_hard());
-    lines.push(format!("|| Hard: {:?}", duration));
-    lines.push(format!("|| {res}"));
-
-    pretty_print(lines);
-}
-
-fn pretty_print(lines: Vec<String>) {
-    let longest = lines.iter().map(|x| x.len()).max().unwrap() + 2;
-    let frame = "=".repeat(longest + 2);
-    println!("{frame}");
-    for line in lines {
-        print!("{line}");
-        let padding = longest - line.chars().count();
-        if padding > 0 {
-            let pad = " ".repeat(padding);
-            print!("{pad}");
+    let command = match cli::parse(env::args().skip(1), &registry.days()) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("error: {e}");
+            eprintln!("Run `all --help` for usage");
+            process::exit(2);
         }
-        println!("||");
+    };
+
+    match command {
+        Command::Help => println!("{USAGE}"),
+        Command::New { year, day, name } => commands::new::run(year, day, &name),
+        Command::Compare {
+            baseline,
+            threshold,
+        } => commands::compare::run(baseline.as_deref(), threshold),
+        Command::Watch { year, day } => watch::watch(year, day),
+        Command::Fetch { year, days } => commands::fetch::run(year, &days),
+        Command::Submit {
+            year,
+            day,
+            part,
+            force,
+        } => commands::submit::run(&registry, year, day, part, force),
+        Command::Sample {
+            year,
+            day,
+            page,
+            force,
+        } => commands::sample::run(year, day, page.as_deref(), force),
+        Command::Crypt { mode, year, days } => commands::crypt::run(mode, year, &days),
+        Command::Dashboard { year } => commands::dashboard::run(&registry, year),
+        Command::List => registry.iter().for_each(|registration| {
+            println!(
+                "{} Day {:02} - {}",
+                registration.year, registration.day, registration.title
+            );
+        }),
+        Command::Run(options) => commands::run::run(&registry, options),
     }
-    println!("{frame}");
-    println!();
 }
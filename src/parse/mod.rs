@@ -0,0 +1,133 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    pub path: String,
+    pub line: usize,
+    pub column: usize,
+    pub snippet: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn at(line: usize, text: &str, offset: usize, message: impl Into<String>) -> Self {
+        ParseError {
+            path: String::new(),
+            line,
+            column: text[..offset].chars().count() + 1,
+            snippet: text.to_string(),
+            message: message.into(),
+        }
+    }
+
+    pub fn in_field(line: usize, text: &str, field: &str, message: impl Into<String>) -> Self {
+        Self::at(line, text, offset(text, field), message)
+    }
+
+    pub fn read(path: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            path: path.to_string(),
+            line: 0,
+            column: 0,
+            snippet: String::new(),
+            message: message.into(),
+        }
+    }
+
+    pub fn in_file(self, path: &str) -> Self {
+        ParseError {
+            path: path.to_string(),
+            ..self
+        }
+    }
+
+    pub fn diagnostic(&self) -> String {
+        let mut lines = vec![format!("error: {}", self.message)];
+        if self.line == 0 {
+            lines.push(format!(" --> {}", self.path));
+            return lines.join("\n");
+        }
+        let gutter = " ".repeat(self.line.to_string().len());
+        let pointer = " ".repeat(self.column - 1);
+        lines.push(format!(
+            "{gutter}--> {}:{}:{}",
+            self.path, self.line, self.column
+        ));
+        lines.push(format!("{gutter} |"));
+        lines.push(format!("{} | {}", self.line, self.snippet));
+        lines.push(format!("{gutter} | {pointer}^"));
+        lines.join("\n")
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match (self.path.is_empty(), self.line) {
+            (true, 0) => write!(f, "{}", self.message),
+            (false, 0) => write!(f, "{}: {}", self.path, self.message),
+            (true, line) => write!(f, "{line}:{}: {}", self.column, self.message),
+            (false, line) => write!(f, "{}:{line}:{}: {}", self.path, self.column, self.message),
+        }
+    }
+}
+
+pub fn number<T: FromStr>(line: usize, text: &str, field: &str) -> Result<T, ParseError> {
+    field.parse::<T>().map_err(|_| {
+        let bad = field
+            .char_indices()
+            .find(|(_, c)| !c.is_ascii_digit())
+            .map_or(0, |(i, _)| i);
+        let message = if field.is_empty() {
+            "expected a number".to_string()
+        } else {
+            format!("expected a number, found `{field}`")
+        };
+        ParseError::in_field(line, text, &field[bad..], message)
+    })
+}
+
+fn offset(text: &str, field: &str) -> usize {
+    field.as_ptr() as usize - text.as_ptr() as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::{number, ParseError};
+
+    #[test]
+    fn test_number() {
+        let text = "12-3x4";
+        let (start, end) = text.split_once('-').unwrap();
+        assert_eq!(number::<u64>(1, text, start), Ok(12));
+        let error = number::<u64>(7, text, end).unwrap_err();
+        assert_eq!((error.line, error.column), (7, 5));
+        assert_eq!(error.snippet, "12-3x4");
+        assert_eq!(error.message, "expected a number, found `3x4`");
+        let error = number::<u64>(1, text, &text[6..]).unwrap_err();
+        assert_eq!(
+            (error.column, error.message.as_str()),
+            (7, "expected a number")
+        );
+    }
+
+    #[test]
+    fn test_diagnostic() {
+        let error = ParseError::at(12, "9876x", 4, "expected a digit, found `x`")
+            .in_file("day_03/input.txt");
+        assert_eq!(
+            error.to_string(),
+            "day_03/input.txt:12:5: expected a digit, found `x`"
+        );
+        assert_eq!(
+            error.diagnostic(),
+            "error: expected a digit, found `x`\n  --> day_03/input.txt:12:5\n   |\n12 | 9876x\n   |     ^"
+        );
+        let error = ParseError::read("missing.txt", "No such file or directory");
+        assert_eq!(error.to_string(), "missing.txt: No such file or directory");
+        assert_eq!(
+            error.diagnostic(),
+            "error: No such file or directory\n --> missing.txt"
+        );
+    }
+}
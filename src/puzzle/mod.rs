@@ -0,0 +1,193 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Default, PartialEq)]
+pub struct Puzzle {
+    pub sample: Option<String>,
+    pub easy: Option<String>,
+    pub hard: Option<String>,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Saved {
+    Written,
+    Kept,
+}
+
+pub fn extract(html: &str) -> Puzzle {
+    let articles = sections(html, "<article", "</article>");
+    let sample = articles
+        .first()
+        .and_then(|article| {
+            sections(article, "<pre><code>", "</code></pre>")
+                .into_iter()
+                .next()
+        })
+        .map(|block| {
+            let text = decode(&strip_tags(block));
+            if text.ends_with('\n') {
+                text
+            } else {
+                format!("{text}\n")
+            }
+        })
+        .filter(|text| !text.trim().is_empty());
+    let answer = |article: Option<&&str>| {
+        let article = article?;
+        let mut highlighted = sections(article, "<code><em>", "</em></code>");
+        highlighted.extend(sections(article, "<em><code>", "</code></em>"));
+        highlighted.sort_by_key(|code| code.as_ptr());
+        highlighted
+            .last()
+            .map(|code| decode(&strip_tags(code)).trim().to_string())
+            .filter(|answer| !answer.is_empty())
+    };
+    Puzzle {
+        sample,
+        easy: answer(articles.first()),
+        hard: answer(articles.get(1)),
+    }
+}
+
+pub fn save(dir: &Path, puzzle: &Puzzle, force: bool) -> io::Result<Vec<(PathBuf, Saved)>> {
+    let mut saved = Vec::new();
+    if let Some(sample) = &puzzle.sample {
+        let path = dir.join("sample.txt");
+        saved.push((path.clone(), write(&path, sample, force)?));
+    }
+    let expected = [("easy", &puzzle.easy), ("hard", &puzzle.hard)]
+        .iter()
+        .filter_map(|(part, answer)| Some(format!("{part}: {}\n", answer.as_ref()?)))
+        .collect::<String>();
+    if !expected.is_empty() {
+        let path = dir.join("sample.expected");
+        saved.push((path.clone(), write(&path, &expected, force)?));
+    }
+    Ok(saved)
+}
+
+fn write(path: &Path, contents: &str, force: bool) -> io::Result<Saved> {
+    if !force && fs::metadata(path).is_ok_and(|m| m.len() > 0) {
+        return Ok(Saved::Kept);
+    }
+    fs::write(path, contents)?;
+    Ok(Saved::Written)
+}
+
+fn sections<'a>(html: &'a str, open: &str, close: &str) -> Vec<&'a str> {
+    let mut found = Vec::new();
+    let mut rest = html;
+    while let Some(start) = rest.find(open) {
+        let inner = &rest[start + open.len()..];
+        let inner = if open.ends_with('>') {
+            inner
+        } else {
+            &inner[inner.find('>').map_or(0, |i| i + 1)..]
+        };
+        let Some(end) = inner.find(close) else {
+            break;
+        };
+        found.push(&inner[..end]);
+        rest = &inner[end + close.len()..];
+    }
+    found
+}
+
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text
+}
+
+fn decode(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::puzzle::{extract, save, Puzzle, Saved};
+    use std::fs;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html><body><main>
+<article class="day-desc"><h2>--- Day 1: Secret Entrance ---</h2>
+<p>For example, suppose the attached document contained the following rotations:</p>
+<pre><code>L68
+L30
+<em>R48</em>
+</code></pre>
+<p>The dial points at <code>0</code> a total of <code><em>3</em></code> times.</p>
+</article>
+<p>Your puzzle answer was <code>1040</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2>
+<pre><code>a &lt; b &amp;&amp; c</code></pre>
+<p>In this example, the password is <em><code>6</code></em>.</p>
+</article>
+</main></body></html>"#;
+
+    #[test]
+    fn test_extract() {
+        assert_eq!(
+            extract(PAGE),
+            Puzzle {
+                sample: Some("L68\nL30\nR48\n".to_string()),
+                easy: Some("3".to_string()),
+                hard: Some("6".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn test_extract_part_one_only() {
+        let page = PAGE.split("<p>Your puzzle answer").next().unwrap();
+        let puzzle = extract(page);
+        assert_eq!(puzzle.easy, Some("3".to_string()));
+        assert_eq!(puzzle.hard, None);
+        assert_eq!(
+            extract("<article><pre><code>1,2 &amp; 3</code></pre></article>").sample,
+            Some("1,2 & 3\n".to_string())
+        );
+        assert_eq!(extract("<html>Not logged in</html>"), Puzzle::default());
+    }
+
+    #[test]
+    fn test_save_keeps_existing_files() {
+        let dir = std::env::temp_dir().join(format!("aoc-puzzle-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("sample.txt"), "").unwrap();
+        fs::write(dir.join("sample.expected"), "easy: 1\n").unwrap();
+
+        let saved = save(&dir, &extract(PAGE), false).unwrap();
+        assert_eq!(saved[0].1, Saved::Written);
+        assert_eq!(saved[1].1, Saved::Kept);
+        assert_eq!(
+            fs::read_to_string(dir.join("sample.txt")).unwrap(),
+            "L68\nL30\nR48\n"
+        );
+        assert_eq!(
+            fs::read_to_string(dir.join("sample.expected")).unwrap(),
+            "easy: 1\n"
+        );
+
+        save(&dir, &extract(PAGE), true).unwrap();
+        assert_eq!(
+            fs::read_to_string(dir.join("sample.expected")).unwrap(),
+            "easy: 3\nhard: 6\n"
+        );
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,297 @@
+use crate::answers::Verdict;
+use crate::helpers::root_path;
+use crate::report::{DayReport, PartReport, Status};
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+
+pub const README_PATH: &str = root_path!("/README.md");
+
+#[derive(Debug, PartialEq)]
+pub struct ReadmeError(String);
+
+impl Display for ReadmeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub fn table(reports: &[DayReport], redact: bool) -> String {
+    let mut lines = vec![
+        "| Day | Title | Stars | Part 1 | Part 2 | Part 1 median | Part 2 median |".to_string(),
+        "|----:|-------|-------|-------:|-------:|--------------:|--------------:|".to_string(),
+    ];
+    for report in reports {
+        let prefix = format!("Day {:02} - ", report.day);
+        let title = report.title.strip_prefix(&prefix).unwrap_or(&report.title);
+        let (easy, hard) = (report.part(1), report.part(2));
+        lines.push(format!(
+            "| {} | {} | {} | {} | {} | {} | {} |",
+            report.day,
+            title.replace('|', "\\|"),
+            stars(&[easy, hard]),
+            answer(report, easy, redact),
+            answer(report, hard, redact),
+            timing(easy),
+            timing(hard),
+        ));
+    }
+    lines.join("\n")
+}
+
+pub fn rewrite(
+    contents: &str,
+    year: usize,
+    table: &str,
+    redact: bool,
+) -> Result<String, ReadmeError> {
+    let (start, end) = (
+        format!("<!-- results-{year}:start -->"),
+        format!("<!-- results-{year}:end -->"),
+    );
+    let Some(from) = contents.find(&start) else {
+        let section = format!("{start}\n{table}\n{end}");
+        return Ok(match contents {
+            "" => format!("{section}\n"),
+            _ if contents.ends_with("\n\n") => format!("{contents}{section}\n"),
+            _ if contents.ends_with('\n') => format!("{contents}\n{section}\n"),
+            _ => format!("{contents}\n\n{section}\n"),
+        });
+    };
+    let Some(to) = contents[from..].find(&end).map(|to| from + to) else {
+        return Err(ReadmeError(format!("`{start}` has no matching `{end}`")));
+    };
+    let table = merge(&contents[from + start.len()..to], table, redact);
+    Ok(format!(
+        "{}{start}\n{table}\n{end}{}",
+        &contents[..from],
+        &contents[to + end.len()..]
+    ))
+}
+
+fn merge(old: &str, table: &str, redact: bool) -> String {
+    let day = |line: &str| {
+        let cell = line.strip_prefix('|')?.split('|').next()?;
+        cell.trim().parse::<usize>().ok()
+    };
+    let mut rows = old
+        .lines()
+        .filter_map(|line| match redact {
+            true => Some((day(line)?, redact_answers(line))),
+            false => Some((day(line)?, line.to_string())),
+        })
+        .collect::<BTreeMap<usize, String>>();
+    let mut header = Vec::new();
+    for line in table.lines() {
+        match day(line) {
+            Some(day) => {
+                rows.insert(day, line.to_string());
+            }
+            None => header.push(line),
+        }
+    }
+    header
+        .into_iter()
+        .map(str::to_string)
+        .chain(rows.into_values())
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn redact_answers(row: &str) -> String {
+    row.split('|')
+        .map(|cell| match cell.trim() {
+            value if value.len() > 1 && value.starts_with('`') && value.ends_with('`') => {
+                " *redacted* "
+            }
+            _ => cell,
+        })
+        .collect::<Vec<&str>>()
+        .join("|")
+}
+
+pub fn update(path: &str, year: usize, table: &str, redact: bool) -> Result<(), ReadmeError> {
+    let contents = match fs::read_to_string(path) {
+        Ok(contents) => contents,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+        Err(e) => return Err(ReadmeError(e.to_string())),
+    };
+    fs::write(path, rewrite(&contents, year, table, redact)?)
+        .map_err(|e| ReadmeError(e.to_string()))
+}
+
+fn stars(parts: &[Option<&PartReport>]) -> String {
+    let count = parts
+        .iter()
+        .flatten()
+        .filter(|p| p.verdict == Some(Verdict::Pass))
+        .count();
+    "⭐".repeat(count)
+}
+
+fn answer(report: &DayReport, part: Option<&PartReport>, redact: bool) -> String {
+    match report.status {
+        Status::Panicked(_) => return "panicked".to_string(),
+        Status::Invalid(_) => return "invalid input".to_string(),
+        _ => {}
+    }
+    match part {
+        None => String::new(),
+        Some(p) if p.timed_out => "timed out".to_string(),
+        Some(p) => match p.answer.value() {
+            None => "-".to_string(),
+            Some(_) if redact => "*redacted*".to_string(),
+            Some(value) => format!("`{value}`"),
+        },
+    }
+}
+
+fn timing(part: Option<&PartReport>) -> String {
+    match part {
+        Some(p) if p.answer.is_solved() => {
+            format!("{:?}", p.stats.as_ref().map_or(p.duration, |s| s.median))
+        }
+        _ => String::new(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::Verdict;
+    use crate::challenges::Solution;
+    use crate::readme::{rewrite, table, ReadmeError};
+    use crate::report::{DayReport, PartReport, Status};
+    use std::time::Duration;
+
+    fn report(day: usize, title: &str, answers: [(Solution, Option<Verdict>); 2]) -> DayReport {
+        DayReport {
+            day,
+            title: format!("Day {day:02} - {title}"),
+            status: Status::Ok,
+            elapsed: Duration::ZERO,
+            load: Duration::ZERO,
+            load_stats: None,
+            parse: Duration::ZERO,
+            parse_stats: None,
+            parts: answers
+                .into_iter()
+                .enumerate()
+                .map(|(i, (answer, verdict))| PartReport {
+                    part: i as u8 + 1,
+                    duration: Duration::from_micros(i as u64 + 1),
+                    answer,
+                    verdict,
+                    stats: None,
+                    timed_out: false,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_table() {
+        let reports = vec![
+            report(
+                1,
+                "Secret Entrance",
+                [
+                    (Solution::labelled("Zeros", 3), Some(Verdict::Pass)),
+                    (
+                        Solution::labelled("Zeros", 6),
+                        Some(Verdict::Fail("7".to_string())),
+                    ),
+                ],
+            ),
+            report(
+                9,
+                "Movie Theater",
+                [(Solution::Unsolved, None), (Solution::Unsolved, None)],
+            ),
+        ];
+        let lines = table(&reports, false);
+        let lines = lines.lines().collect::<Vec<&str>>();
+        assert_eq!(lines.len(), 4);
+        assert_eq!(
+            lines[2],
+            "| 1 | Secret Entrance | ⭐ | `3` | `6` | 1µs | 2µs |"
+        );
+        assert_eq!(lines[3], "| 9 | Movie Theater |  | - | - |  |  |");
+        assert!(table(&reports, true).contains("| *redacted* | *redacted* |"));
+    }
+
+    #[test]
+    fn test_rewrite_replaces_only_the_marked_section() {
+        let readme =
+            "# Title\n\n<!-- results-2025:start -->\nold\n<!-- results-2025:end -->\n\nFooter\n";
+        assert_eq!(
+            rewrite(readme, 2025, "new", false).unwrap(),
+            "# Title\n\n<!-- results-2025:start -->\nnew\n<!-- results-2025:end -->\n\nFooter\n"
+        );
+    }
+
+    #[test]
+    fn test_rewrite_merges_rows_by_day() {
+        let readme = "<!-- results-2025:start -->\n| Day | Old |\n|--|--|\n| 1 | a |\n| 3 | b |\n\
+<!-- results-2025:end -->\n";
+        assert_eq!(
+            rewrite(readme, 2025, "| Day | New |\n|--|--|\n| 3 | c |\n| 2 | d |", false).unwrap(),
+            "<!-- results-2025:start -->\n| Day | New |\n|--|--|\n| 1 | a |\n| 2 | d |\n| 3 | c |\n\
+<!-- results-2025:end -->\n"
+        );
+    }
+
+    #[test]
+    fn test_rewrite_redacts_kept_rows() {
+        let readme = "<!-- results-2025:start -->\n| Day | Part 1 | Part 2 |\n|--|--|--|\n\
+| 1 | `1040` | `6027` |\n| 9 | - | - |\n<!-- results-2025:end -->\n";
+        let table = "| Day | Part 1 | Part 2 |\n|--|--|--|\n| 2 | *redacted* | *redacted* |";
+        assert_eq!(
+            rewrite(readme, 2025, table, true).unwrap(),
+            "<!-- results-2025:start -->\n| Day | Part 1 | Part 2 |\n|--|--|--|\n\
+| 1 | *redacted* | *redacted* |\n| 2 | *redacted* | *redacted* |\n| 9 | - | - |\n\
+<!-- results-2025:end -->\n"
+        );
+    }
+
+    #[test]
+    fn test_rewrite_keeps_other_years() {
+        let readme = "<!-- results-2024:start -->\nold\n<!-- results-2024:end -->\n";
+        assert_eq!(
+            rewrite(readme, 2025, "new", false).unwrap(),
+            format!("{readme}\n<!-- results-2025:start -->\nnew\n<!-- results-2025:end -->\n")
+        );
+        assert_eq!(
+            rewrite(readme, 2024, "new", false).unwrap(),
+            "<!-- results-2024:start -->\nnew\n<!-- results-2024:end -->\n"
+        );
+    }
+
+    #[test]
+    fn test_rewrite_appends_missing_section() {
+        assert_eq!(
+            rewrite("# Title\nAdvent of Code", 2025, "new", false).unwrap(),
+            "# Title\nAdvent of Code\n\n<!-- results-2025:start -->\nnew\n<!-- results-2025:end -->\n"
+        );
+        assert_eq!(
+            rewrite("# Title\n", 2025, "new", false).unwrap(),
+            "# Title\n\n<!-- results-2025:start -->\nnew\n<!-- results-2025:end -->\n"
+        );
+        assert_eq!(
+            rewrite("", 2025, "new", false).unwrap(),
+            "<!-- results-2025:start -->\nnew\n<!-- results-2025:end -->\n"
+        );
+    }
+
+    #[test]
+    fn test_rewrite_rejects_unterminated_section() {
+        let readme = "# Title\n\n<!-- results-2025:start -->\nold\n\nFooter\n";
+        assert_eq!(
+            rewrite(readme, 2025, "new", false),
+            Err(ReadmeError(
+                "`<!-- results-2025:start -->` has no matching `<!-- results-2025:end -->`"
+                    .to_string()
+            ))
+        );
+    }
+}
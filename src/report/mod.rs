@@ -0,0 +1,557 @@
+use crate::answers::Verdict;
+use crate::bench::Stats;
+use crate::challenges::Solution;
+use crate::parse::ParseError;
+use std::cmp::Reverse;
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Pretty,
+    Json,
+    Csv,
+    Ndjson,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Status {
+    Ok,
+    Panicked(String),
+    TimedOut(String),
+    Error(String),
+    Invalid(ParseError),
+}
+
+impl Status {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Status::Ok => "ok",
+            Status::Panicked(_) => "panicked",
+            Status::TimedOut(_) => "timeout",
+            Status::Error(_) => "error",
+            Status::Invalid(_) => "invalid",
+        }
+    }
+
+    pub fn message(&self) -> Option<String> {
+        match self {
+            Status::Ok => None,
+            Status::Panicked(message) | Status::TimedOut(message) | Status::Error(message) => {
+                Some(message.clone())
+            }
+            Status::Invalid(error) => Some(error.to_string()),
+        }
+    }
+}
+
+pub struct DayReport {
+    pub day: usize,
+    pub title: String,
+    pub status: Status,
+    pub elapsed: Duration,
+    pub load: Duration,
+    pub load_stats: Option<Stats>,
+    pub parse: Duration,
+    pub parse_stats: Option<Stats>,
+    pub parts: Vec<PartReport>,
+}
+
+pub struct PartReport {
+    pub part: u8,
+    pub duration: Duration,
+    pub answer: Solution,
+    pub verdict: Option<Verdict>,
+    pub stats: Option<Stats>,
+    pub timed_out: bool,
+}
+
+impl DayReport {
+    pub fn part(&self, part: u8) -> Option<&PartReport> {
+        self.parts.iter().find(|p| p.part == part)
+    }
+
+    pub fn failures(&self) -> Vec<String> {
+        self.parts
+            .iter()
+            .filter_map(|p| match &p.verdict {
+                Some(Verdict::Fail(expected)) => Some(format!(
+                    "Day {:02} part {}: got `{}`, expected `{expected}`",
+                    self.day, p.part, p.answer
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
+    pub fn passed(&self) -> bool {
+        self.status == Status::Ok
+    }
+}
+
+pub struct Printer {
+    format: Format,
+    bench: bool,
+    printed: usize,
+    statuses: Vec<(usize, Status, Duration)>,
+}
+
+impl Printer {
+    pub fn new(format: Format, bench: bool) -> Self {
+        Printer {
+            format,
+            bench,
+            printed: 0,
+            statuses: Vec::new(),
+        }
+    }
+
+    pub fn begin(&mut self) {
+        match self.format {
+            Format::Json => println!("["),
+            Format::Csv if self.bench => println!("{}", CSV_BENCH_HEADER),
+            Format::Csv => println!("{}", CSV_HEADER),
+            Format::Pretty | Format::Ndjson => {}
+        }
+    }
+
+    pub fn print(&mut self, report: &DayReport) {
+        match self.format {
+            Format::Pretty => pretty_print(pretty_lines(report)),
+            Format::Json => {
+                if self.printed > 0 {
+                    println!(",");
+                }
+                print!("  {}", json_record(report));
+            }
+            Format::Ndjson => println!("{}", json_record(report)),
+            Format::Csv if self.bench => csv_bench_records(report)
+                .iter()
+                .for_each(|record| println!("{record}")),
+            Format::Csv => println!("{}", csv_record(report)),
+        }
+        self.printed += 1;
+        self.statuses
+            .push((report.day, report.status.clone(), report.elapsed));
+    }
+
+    pub fn finish(&mut self) {
+        if self.format == Format::Json {
+            if self.printed > 0 {
+                println!();
+            }
+            println!("]");
+        }
+    }
+
+    pub fn summary(&self, wall_time: Duration) {
+        let all_ok = self.statuses.iter().all(|(_, s, _)| *s == Status::Ok);
+        if self.statuses.len() < 2 && all_ok {
+            return;
+        }
+        let mut lines = vec!["|| Summary".to_string(), "||".to_string()];
+        for (day, status, _) in &self.statuses {
+            let line = match status.message() {
+                Some(message) => format!("|| Day {day:02}  {:<8}  {message}", status.name()),
+                None => format!("|| Day {day:02}  {}", status.name()),
+            };
+            lines.push(line);
+        }
+        let count = |name: &str| {
+            self.statuses
+                .iter()
+                .filter(|(_, s, _)| s.name() == name)
+                .count()
+        };
+        lines.push("||".to_string());
+        lines.push(format!(
+            "|| {} ok, {} panicked, {} timeout, {} error, {} invalid",
+            count("ok"),
+            count("panicked"),
+            count("timeout"),
+            count("error"),
+            count("invalid")
+        ));
+
+        let total = self.statuses.iter().map(|(_, _, d)| *d).sum::<Duration>();
+        let mut slowest = self.statuses.iter().collect::<Vec<_>>();
+        slowest.sort_by_key(|(_, _, d)| Reverse(*d));
+        let slowest = slowest
+            .iter()
+            .take(3)
+            .map(|(day, _, d)| format!("Day {day:02} ({d:?})"))
+            .collect::<Vec<String>>()
+            .join(", ");
+        lines.push("||".to_string());
+        lines.push(format!("|| Wall time: {wall_time:?}"));
+        lines.push(format!("|| Sum of day times: {total:?}"));
+        lines.push(format!("|| Slowest: {slowest}"));
+        match self.format {
+            Format::Pretty => pretty_print(lines),
+            _ => lines.iter().for_each(|line| eprintln!("{line}")),
+        }
+    }
+}
+
+fn pretty_lines(report: &DayReport) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    lines.push(format!("|| {}", report.title));
+    if let Status::Panicked(message) = &report.status {
+        lines.push(format!("|| PANICKED: {message}"));
+        return lines;
+    }
+    if let Status::Invalid(error) = &report.status {
+        lines.push(format!("|| INVALID INPUT: {error}"));
+        return lines;
+    }
+    if let Some(stats) = &report.parse_stats {
+        lines.push(format!("|| Iterations: {}", stats.iterations));
+    }
+    push_timing(&mut lines, "Load", report.load, &report.load_stats);
+    push_timing(&mut lines, "Parse", report.parse, &report.parse_stats);
+    for part in &report.parts {
+        let name = if part.part == 1 { "Easy" } else { "Hard" };
+        lines.push("||".to_string());
+        if part.timed_out {
+            lines.push(format!("|| {name}: TIMEOUT after {:?}", part.duration));
+            continue;
+        }
+        push_timing(&mut lines, name, part.duration, &part.stats);
+        lines.push(format!("|| {}", part.answer));
+        match &part.verdict {
+            Some(Verdict::Pass) => lines.push("|| PASS".to_string()),
+            Some(Verdict::Fail(expected)) => lines.push(format!("|| FAIL (expected {expected})")),
+            Some(Verdict::Missing) => lines.push("|| No expected answer".to_string()),
+            None => {}
+        }
+    }
+    lines
+}
+
+fn push_timing(lines: &mut Vec<String>, name: &str, duration: Duration, stats: &Option<Stats>) {
+    let Some(stats) = stats else {
+        lines.push(format!("|| {name}: {:?}", duration));
+        return;
+    };
+    lines.push(format!(
+        "|| {name}: median {:?} | mean {:?} | min {:?} | p95 {:?} | stddev {:?}",
+        stats.median, stats.mean, stats.min, stats.p95, stats.stddev
+    ));
+    if !stats.outliers.is_empty() {
+        let outliers = stats
+            .outliers
+            .iter()
+            .map(|o| format!("#{} ({:?})", o.iteration, o.duration))
+            .collect::<Vec<String>>()
+            .join(", ");
+        lines.push(format!("|| Outliers: {outliers}"));
+    }
+}
+
+pub fn pretty_print(lines: Vec<String>) {
+    let longest = lines.iter().map(|x| x.len()).max().unwrap() + 2;
+    let frame = "=".repeat(longest + 2);
+    println!("{frame}");
+    for line in lines {
+        print!("{line}");
+        let padding = longest - line.chars().count();
+        if padding > 0 {
+            let pad = " ".repeat(padding);
+            print!("{pad}");
+        }
+        println!("||");
+    }
+    println!("{frame}");
+    println!();
+}
+
+fn verdict_name(verdict: &Option<Verdict>) -> Option<&'static str> {
+    verdict.as_ref().map(|v| match v {
+        Verdict::Pass => "pass",
+        Verdict::Fail(_) => "fail",
+        Verdict::Missing => "missing",
+    })
+}
+
+fn json_record(report: &DayReport) -> String {
+    let parts = report
+        .parts
+        .iter()
+        .map(|p| {
+            let answer = p
+                .answer
+                .value()
+                .map(|value| json_string(&value.to_string()))
+                .unwrap_or("null".to_string());
+            let label = p.answer.label().map(json_string).unwrap_or("null".to_string());
+            let verdict = verdict_name(&p.verdict)
+                .map(json_string)
+                .unwrap_or("null".to_string());
+            format!(
+                "{{\"part\":{},\"duration_ns\":{},\"answer\":{},\"label\":{},\"timed_out\":{},\"verdict\":{},\"stats\":{}}}",
+                p.part,
+                p.duration.as_nanos(),
+                answer,
+                label,
+                p.timed_out,
+                verdict,
+                json_stats(&p.stats)
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(",");
+    let message = report
+        .status
+        .message()
+        .as_deref()
+        .map(json_string)
+        .unwrap_or("null".to_string());
+    format!(
+        "{{\"day\":{},\"title\":{},\"status\":{},\"message\":{},\"load_ns\":{},\"load_stats\":{},\"parse_ns\":{},\"parse_stats\":{},\"parts\":[{}]}}",
+        report.day,
+        json_string(&report.title),
+        json_string(report.status.name()),
+        message,
+        report.load.as_nanos(),
+        json_stats(&report.load_stats),
+        report.parse.as_nanos(),
+        json_stats(&report.parse_stats),
+        parts
+    )
+}
+
+fn json_stats(stats: &Option<Stats>) -> String {
+    let Some(stats) = stats else {
+        return "null".to_string();
+    };
+    let outliers = stats
+        .outliers
+        .iter()
+        .map(|o| {
+            format!(
+                "{{\"iteration\":{},\"duration_ns\":{}}}",
+                o.iteration,
+                o.duration.as_nanos()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join(",");
+    format!(
+        "{{\"iterations\":{},\"min_ns\":{},\"median_ns\":{},\"mean_ns\":{},\"p95_ns\":{},\"stddev_ns\":{},\"outliers\":[{}]}}",
+        stats.iterations,
+        stats.min.as_nanos(),
+        stats.median.as_nanos(),
+        stats.mean.as_nanos(),
+        stats.p95.as_nanos(),
+        stats.stddev.as_nanos(),
+        outliers
+    )
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+    for ch in value.chars() {
+        match ch {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            ch if (ch as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => escaped.push(ch),
+        }
+    }
+    escaped.push('"');
+    escaped
+}
+
+const CSV_HEADER: &str = "day,title,status,message,load_ns,parse_ns,\
+part1_duration_ns,part1_answer,part1_verdict,\
+part2_duration_ns,part2_answer,part2_verdict";
+
+fn csv_record(report: &DayReport) -> String {
+    let mut fields = vec![
+        report.day.to_string(),
+        csv_field(&report.title),
+        report.status.name().to_string(),
+        csv_field(&report.status.message().unwrap_or_default()),
+        report.load.as_nanos().to_string(),
+        report.parse.as_nanos().to_string(),
+    ];
+    for part in 1..=2 {
+        match report.part(part) {
+            Some(p) => {
+                fields.push(p.duration.as_nanos().to_string());
+                fields.push(csv_field(&answer_field(&p.answer)));
+                fields.push(verdict_name(&p.verdict).unwrap_or("").to_string());
+            }
+            None => fields.extend(["", "", ""].map(String::from)),
+        }
+    }
+    fields.join(",")
+}
+
+fn answer_field(answer: &Solution) -> String {
+    answer
+        .value()
+        .map(|value| value.to_string())
+        .unwrap_or_default()
+}
+
+const CSV_BENCH_HEADER: &str =
+    "day,title,phase,answer,iterations,min_ns,median_ns,mean_ns,p95_ns,stddev_ns,outliers";
+
+fn csv_bench_records(report: &DayReport) -> Vec<String> {
+    let mut phases = vec![
+        ("load", String::new(), &report.load_stats),
+        ("parse", String::new(), &report.parse_stats),
+    ];
+    for part in &report.parts {
+        let phase = if part.part == 1 { "part1" } else { "part2" };
+        phases.push((phase, answer_field(&part.answer), &part.stats));
+    }
+    phases
+        .into_iter()
+        .filter_map(|(phase, answer, stats)| stats.as_ref().map(|s| (phase, answer, s)))
+        .map(|(phase, answer, stats)| {
+            [
+                report.day.to_string(),
+                csv_field(&report.title),
+                phase.to_string(),
+                csv_field(&answer),
+                stats.iterations.to_string(),
+                stats.min.as_nanos().to_string(),
+                stats.median.as_nanos().to_string(),
+                stats.mean.as_nanos().to_string(),
+                stats.p95.as_nanos().to_string(),
+                stats.stddev.as_nanos().to_string(),
+                stats.outliers.len().to_string(),
+            ]
+            .join(",")
+        })
+        .collect()
+}
+
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn split_csv(line: &str) -> Vec<String> {
+    let mut fields = vec![String::new()];
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                chars.next();
+                fields.last_mut().unwrap().push('"');
+            }
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(String::new()),
+            _ => fields.last_mut().unwrap().push(c),
+        }
+    }
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::Verdict;
+    use crate::bench::Stats;
+    use crate::challenges::Solution;
+    use crate::report::{
+        csv_bench_records, csv_field, csv_record, json_record, json_string, split_csv, DayReport,
+        PartReport, Status,
+    };
+    use std::time::Duration;
+
+    fn report() -> DayReport {
+        DayReport {
+            day: 1,
+            title: "Day 01 - Secret Entrance".to_string(),
+            status: Status::Ok,
+            elapsed: Duration::from_micros(4),
+            load: Duration::from_nanos(800),
+            load_stats: None,
+            parse: Duration::from_nanos(1500),
+            parse_stats: None,
+            parts: vec![PartReport {
+                part: 2,
+                duration: Duration::from_micros(2),
+                answer: Solution::labelled("Zeros", 6),
+                verdict: Some(Verdict::Pass),
+                stats: None,
+                timed_out: false,
+            }],
+        }
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("plain"), "\"plain\"");
+        assert_eq!(json_string("a \"b\" \\ c\n"), "\"a \\\"b\\\" \\\\ c\\n\"");
+        assert_eq!(json_string("\u{1}"), "\"\\u0001\"");
+    }
+
+    #[test]
+    fn test_json_record() {
+        assert_eq!(
+            json_record(&report()),
+            "{\"day\":1,\"title\":\"Day 01 - Secret Entrance\",\"status\":\"ok\",\"message\":null,\"load_ns\":800,\"load_stats\":null,\"parse_ns\":1500,\"parse_stats\":null,\
+            \"parts\":[{\"part\":2,\"duration_ns\":2000,\"answer\":\"6\",\"label\":\"Zeros\",\"timed_out\":false,\"verdict\":\"pass\",\"stats\":null}]}"
+        );
+    }
+
+    #[test]
+    fn test_json_record_keeps_large_answers_exact() {
+        let mut report = report();
+        report.parts[0].answer = Solution::labelled("Zeros", 9_007_199_254_740_993_i64);
+        assert!(json_record(&report).contains("\"answer\":\"9007199254740993\""));
+    }
+
+    #[test]
+    fn test_bench_records() {
+        let mut report = report();
+        let stats = Stats::from_samples(&[Duration::from_nanos(10), Duration::from_nanos(20)]);
+        report.parse_stats = Some(stats.clone());
+        report.parts[0].stats = Some(stats);
+        assert!(json_record(&report).contains(
+            "\"stats\":{\"iterations\":2,\"min_ns\":10,\"median_ns\":15,\"mean_ns\":15,\"p95_ns\":20,\"stddev_ns\":7,\"outliers\":[]}"
+        ));
+        assert_eq!(
+            csv_bench_records(&report),
+            vec![
+                "1,Day 01 - Secret Entrance,parse,,2,10,15,15,20,7,0",
+                "1,Day 01 - Secret Entrance,part2,6,2,10,15,15,20,7,0",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_csv_field() {
+        assert_eq!(csv_field("Zeros: 3"), "Zeros: 3");
+        assert_eq!(csv_field("a,b"), "\"a,b\"");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+
+    #[test]
+    fn test_split_csv() {
+        assert_eq!(split_csv("1,a b,"), vec!["1", "a b", ""]);
+        assert_eq!(
+            split_csv("\"a,b\",\"say \"\"hi\"\"\""),
+            vec!["a,b", "say \"hi\""]
+        );
+    }
+
+    #[test]
+    fn test_csv_record() {
+        assert_eq!(
+            csv_record(&report()),
+            "1,Day 01 - Secret Entrance,ok,,800,1500,,,,2000,6,pass"
+        );
+    }
+}
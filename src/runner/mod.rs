@@ -0,0 +1,565 @@
+use crate::answers::{check, Expected};
+use crate::bench::{Bench, Stats};
+use crate::challenges::{Challenge, Constructor, Solution};
+use crate::cli::Part;
+use crate::helpers::{time_it, Cancellation, Input, Source};
+use crate::parse::ParseError;
+use crate::report::{DayReport, PartReport, Status};
+use std::any::Any;
+use std::cell::{Cell, RefCell};
+use std::collections::BTreeMap;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+thread_local! {
+    static ISOLATED: Cell<bool> = const { Cell::new(false) };
+    static LAST_PANIC: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+pub fn install_panic_hook() {
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if !ISOLATED.get() {
+            return default_hook(info);
+        }
+        let message = payload_message(info.payload());
+        let message = match info.location() {
+            Some(location) => format!("{message} ({}:{})", location.file(), location.line()),
+            None => message,
+        };
+        LAST_PANIC.set(Some(message));
+    }));
+}
+
+fn payload_message(payload: &dyn Any) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "Box<dyn Any>".to_string()
+    }
+}
+
+pub struct Plan<'a> {
+    pub year: usize,
+    pub input: &'a Input,
+    pub part: Part,
+    pub bench: Option<Bench>,
+    pub timeout: Option<Duration>,
+    pub day_timeout: Option<Duration>,
+}
+
+struct Sample {
+    load: Duration,
+    parse: Duration,
+    easy: Option<PartRun>,
+    hard: Option<PartRun>,
+    title: String,
+}
+
+struct PartRun {
+    answer: Option<Solution>,
+    duration: Duration,
+}
+
+type Solver = fn(&mut dyn Challenge, &Cancellation) -> Solution;
+
+pub fn run_days<F, G>(days: &[usize], jobs: usize, run: F, mut on_report: G)
+where
+    F: Fn(usize) -> DayReport + Sync,
+    G: FnMut(DayReport),
+{
+    let next = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel();
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, days.len().max(1)) {
+            let (tx, next, run) = (tx.clone(), &next, &run);
+            scope.spawn(move || loop {
+                let i = next.fetch_add(1, Ordering::SeqCst);
+                if i >= days.len() {
+                    break;
+                }
+                if tx.send((i, run(days[i]))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(tx);
+
+        let mut pending = BTreeMap::new();
+        let mut next_in_order = 0;
+        for (i, report) in rx {
+            pending.insert(i, report);
+            while let Some(report) = pending.remove(&next_in_order) {
+                on_report(report);
+                next_in_order += 1;
+            }
+        }
+    });
+}
+
+pub fn run_challenge(
+    day: usize,
+    ctor: Constructor,
+    plan: &Plan,
+    expected: Option<Expected>,
+) -> DayReport {
+    let start = Instant::now();
+    ISOLATED.set(true);
+    let result = panic::catch_unwind(AssertUnwindSafe(|| run_isolated(day, ctor, plan, expected)));
+    ISOLATED.set(false);
+    match result {
+        Ok(Ok(mut report)) => {
+            let failures = report.failures();
+            let timed_out = report
+                .parts
+                .iter()
+                .filter(|p| p.timed_out)
+                .map(|p| p.part.to_string())
+                .collect::<Vec<String>>();
+            if !timed_out.is_empty() {
+                let limits = [
+                    plan.timeout.map(|t| format!("{t:?} per part")),
+                    plan.day_timeout.map(|t| format!("{t:?} per day")),
+                ];
+                report.status = Status::TimedOut(format!(
+                    "part {} exceeded the limit of {}",
+                    timed_out.join(" and "),
+                    limits.into_iter().flatten().collect::<Vec<_>>().join(", ")
+                ));
+            } else if !failures.is_empty() {
+                report.status = Status::Error(format!(
+                    "{} answer(s) did not match the expected value",
+                    failures.len()
+                ));
+            }
+            report.elapsed = start.elapsed();
+            report
+        }
+        Ok(Err(error)) => DayReport {
+            day,
+            title: format!("Day {day:02}"),
+            status: Status::Invalid(error),
+            elapsed: start.elapsed(),
+            load: Duration::ZERO,
+            load_stats: None,
+            parse: Duration::ZERO,
+            parse_stats: None,
+            parts: Vec::new(),
+        },
+        Err(payload) => {
+            let message = LAST_PANIC
+                .take()
+                .unwrap_or_else(|| payload_message(payload.as_ref()));
+            DayReport {
+                day,
+                title: format!("Day {day:02}"),
+                status: Status::Panicked(message),
+                elapsed: start.elapsed(),
+                load: Duration::ZERO,
+                load_stats: None,
+                parse: Duration::ZERO,
+                parse_stats: None,
+                parts: Vec::new(),
+            }
+        }
+    }
+}
+
+fn run_isolated(
+    day: usize,
+    ctor: Constructor,
+    plan: &Plan,
+    expected: Option<Expected>,
+) -> Result<DayReport, ParseError> {
+    let (iterations, warmup) = plan
+        .bench
+        .map(|b| (b.iterations, b.warmup))
+        .unwrap_or((1, 0));
+    for _ in 0..warmup {
+        run_once(day, ctor, plan)?;
+    }
+    let samples = (0..iterations)
+        .map(|_| run_once(day, ctor, plan))
+        .collect::<Result<Vec<Sample>, ParseError>>()?;
+
+    let (load, load_stats) = phase(samples.iter().map(|s| s.load), plan);
+    let (parse, parse_stats) = phase(samples.iter().map(|s| s.parse), plan);
+    let easy = samples
+        .iter()
+        .filter_map(|s| s.easy.as_ref())
+        .collect::<Vec<_>>();
+    let hard = samples
+        .iter()
+        .filter_map(|s| s.hard.as_ref())
+        .collect::<Vec<_>>();
+    let expected = expected.as_ref();
+
+    let mut parts = Vec::new();
+    if plan.part.includes_easy() {
+        parts.push(part_report(
+            1,
+            &easy,
+            expected.map(|e| e.easy.as_ref()),
+            plan,
+        ));
+    }
+    if plan.part.includes_hard() {
+        parts.push(part_report(
+            2,
+            &hard,
+            expected.map(|e| e.hard.as_ref()),
+            plan,
+        ));
+    }
+
+    Ok(DayReport {
+        day,
+        title: samples.last().unwrap().title.clone(),
+        status: Status::Ok,
+        elapsed: Duration::ZERO,
+        load,
+        load_stats,
+        parse,
+        parse_stats,
+        parts,
+    })
+}
+
+fn phase(durations: impl Iterator<Item = Duration>, plan: &Plan) -> (Duration, Option<Stats>) {
+    let durations = durations.collect::<Vec<_>>();
+    let stats = plan.bench.map(|_| Stats::from_samples(&durations));
+    let duration = stats.as_ref().map(|s| s.median).unwrap_or(durations[0]);
+    (duration, stats)
+}
+
+fn run_once(day: usize, ctor: Constructor, plan: &Plan) -> Result<Sample, ParseError> {
+    let deadline = plan.day_timeout.map(|limit| Instant::now() + limit);
+    let (source, load) = time_it(|| plan.input.load(plan.year, day));
+    let source = source?;
+    let (c, parse) = time_it(|| construct(ctor, &source));
+    let c = c?;
+    let title = c.preamble();
+    let mut state = Some(c);
+    let easy = plan.part.includes_easy().then(|| {
+        let limit = part_limit(plan, deadline);
+        run_part(&mut state, day, &source, ctor, limit, |c, cancel| {
+            c.run_easy(cancel)
+        })
+    });
+    let hard = plan.part.includes_hard().then(|| {
+        let limit = part_limit(plan, deadline);
+        run_part(&mut state, day, &source, ctor, limit, |c, cancel| {
+            c.run_hard(cancel)
+        })
+    });
+    Ok(Sample {
+        load,
+        parse,
+        easy,
+        hard,
+        title,
+    })
+}
+
+fn construct(ctor: Constructor, source: &Source) -> Result<Box<dyn Challenge>, ParseError> {
+    ctor(&source.text).map_err(|e| e.in_file(&source.path))
+}
+
+fn part_limit(plan: &Plan, deadline: Option<Instant>) -> Option<Duration> {
+    let remaining = deadline.map(|d| d.saturating_duration_since(Instant::now()));
+    match (plan.timeout, remaining) {
+        (Some(timeout), Some(remaining)) => Some(timeout.min(remaining)),
+        (timeout, remaining) => timeout.or(remaining),
+    }
+}
+
+fn run_part(
+    state: &mut Option<Box<dyn Challenge>>,
+    day: usize,
+    source: &Source,
+    ctor: Constructor,
+    timeout: Option<Duration>,
+    solve: Solver,
+) -> PartRun {
+    // A timed out part keeps its state on the abandoned thread, so start afresh
+    let mut c = state
+        .take()
+        .unwrap_or_else(|| construct(ctor, source).unwrap_or_else(|e| panic!("{e}")));
+    if timeout.is_some_and(|t| t.is_zero()) {
+        return PartRun {
+            answer: None,
+            duration: Duration::ZERO,
+        };
+    }
+    let Some(timeout) = timeout else {
+        let (answer, duration) = time_it(|| solve(c.as_mut(), &Cancellation::new()));
+        *state = Some(c);
+        return PartRun {
+            answer: Some(answer),
+            duration,
+        };
+    };
+
+    let cancel = Cancellation::new();
+    let worker_cancel = cancel.clone();
+    let (tx, rx) = mpsc::channel();
+    thread::spawn(move || {
+        ISOLATED.set(true);
+        let result = panic::catch_unwind(AssertUnwindSafe(|| {
+            time_it(|| solve(c.as_mut(), &worker_cancel))
+        }));
+        let result = result.map(|r| (c, r)).map_err(|payload| {
+            LAST_PANIC
+                .take()
+                .unwrap_or_else(|| payload_message(payload.as_ref()))
+        });
+        let _ = tx.send(result);
+    });
+
+    match rx.recv_timeout(timeout) {
+        Ok(Ok((c, (answer, duration)))) => {
+            *state = Some(c);
+            PartRun {
+                answer: Some(answer),
+                duration,
+            }
+        }
+        Ok(Err(message)) => {
+            LAST_PANIC.set(Some(message.clone()));
+            panic::resume_unwind(Box::new(message))
+        }
+        Err(RecvTimeoutError::Timeout) => {
+            cancel.cancel();
+            PartRun {
+                answer: None,
+                duration: timeout,
+            }
+        }
+        Err(RecvTimeoutError::Disconnected) => panic!("Day {day} stopped without an answer"),
+    }
+}
+
+fn part_report(
+    part: u8,
+    runs: &[&PartRun],
+    expected: Option<Option<&String>>,
+    plan: &Plan,
+) -> PartReport {
+    if let Some(run) = runs.iter().find(|r| r.answer.is_none()) {
+        return PartReport {
+            part,
+            duration: run.duration,
+            answer: Solution::Unsolved,
+            verdict: None,
+            stats: None,
+            timed_out: true,
+        };
+    }
+    let run = runs.last().unwrap();
+    let answer = run.answer.clone().unwrap();
+    let durations = runs.iter().map(|r| r.duration).collect::<Vec<_>>();
+    let stats = plan.bench.map(|_| Stats::from_samples(&durations));
+    PartReport {
+        part,
+        duration: stats.as_ref().map(|s| s.median).unwrap_or(run.duration),
+        verdict: expected.map(|e| check(&answer, e)),
+        answer,
+        stats,
+        timed_out: false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::Expected;
+    use crate::bench::Bench;
+    use crate::challenges::{Challenge, Solution};
+    use crate::cli::Part;
+    use crate::helpers::{day_dir, Cancellation, Input, Reader};
+    use crate::parse::ParseError;
+    use crate::report::{DayReport, Status};
+    use crate::runner::{install_panic_hook, run_challenge, run_days, Plan};
+    use std::thread;
+    use std::time::Duration;
+
+    struct Stub {}
+
+    impl Challenge for Stub {
+        fn preamble(&self) -> String {
+            "Day 01 - Stub".to_string()
+        }
+
+        fn run_easy(&mut self, _cancel: &Cancellation) -> Solution {
+            Solution::labelled("Answer", 3)
+        }
+
+        fn run_hard(&mut self, _cancel: &Cancellation) -> Solution {
+            panic!("Hard part exploded")
+        }
+    }
+
+    fn stub(_: &str) -> Result<Box<dyn Challenge>, ParseError> {
+        Ok(Box::new(Stub {}))
+    }
+
+    fn invalid(input: &str) -> Result<Box<dyn Challenge>, ParseError> {
+        let (line, text) = Reader::from_text(input).numbered().nth(1).unwrap();
+        Err(ParseError::at(line, &text, 1, "expected a digit"))
+    }
+
+    struct Spinner {}
+
+    impl Challenge for Spinner {
+        fn preamble(&self) -> String {
+            "Day 01 - Spinner".to_string()
+        }
+
+        fn run_easy(&mut self, cancel: &Cancellation) -> Solution {
+            while !cancel.is_cancelled() {
+                thread::sleep(Duration::from_millis(1));
+            }
+            Solution::Unsolved
+        }
+
+        fn run_hard(&mut self, _cancel: &Cancellation) -> Solution {
+            Solution::labelled("Answer", 4)
+        }
+    }
+
+    fn spinner(_: &str) -> Result<Box<dyn Challenge>, ParseError> {
+        Ok(Box::new(Spinner {}))
+    }
+
+    fn plan(part: Part, timeout: Option<Duration>) -> Plan<'static> {
+        Plan {
+            year: 2025,
+            input: &Input::Sample,
+            part,
+            bench: None,
+            timeout,
+            day_timeout: None,
+        }
+    }
+
+    #[test]
+    fn test_ok_and_error_status() {
+        let expected = Expected {
+            easy: Some("3".to_string()),
+            hard: None,
+        };
+        let report = run_challenge(1, stub, &plan(Part::Easy, None), Some(expected));
+        assert_eq!(report.status, Status::Ok);
+        assert_eq!(report.parts[0].answer, Solution::labelled("Answer", 3));
+
+        let expected = Expected {
+            easy: Some("4".to_string()),
+            hard: None,
+        };
+        let report = run_challenge(1, stub, &plan(Part::Easy, None), Some(expected));
+        assert!(matches!(report.status, Status::Error(_)));
+    }
+
+    #[test]
+    fn test_phases_are_timed_separately() {
+        let plan = Plan {
+            bench: Some(Bench {
+                iterations: 3,
+                warmup: 1,
+            }),
+            ..plan(Part::Easy, None)
+        };
+        let report = run_challenge(1, stub, &plan, None);
+        let load = report.load_stats.unwrap();
+        let parse = report.parse_stats.unwrap();
+        assert_eq!((load.iterations, parse.iterations), (3, 3));
+        assert_eq!((report.load, report.parse), (load.median, parse.median));
+        assert_eq!(report.parts[0].stats.as_ref().unwrap().iterations, 3);
+    }
+
+    #[test]
+    fn test_panics_are_isolated() {
+        install_panic_hook();
+        let report = run_challenge(1, stub, &plan(Part::Both, None), None);
+        let Status::Panicked(message) = report.status else {
+            panic!("Expected the day to panic, got {:?}", report.status);
+        };
+        assert!(message.starts_with("Hard part exploded"));
+        assert!(report.parts.is_empty());
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let report = run_challenge(1, invalid, &plan(Part::Both, None), None);
+        let Status::Invalid(error) = report.status else {
+            panic!("Expected invalid input, got {:?}", report.status);
+        };
+        assert_eq!(error.path, format!("{}/sample.txt", day_dir(2025, 1)));
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.snippet, "L30");
+        assert!(report.parts.is_empty());
+    }
+
+    #[test]
+    fn test_timeouts() {
+        let timeout = Some(Duration::from_millis(20));
+        let report = run_challenge(1, spinner, &plan(Part::Both, timeout), None);
+        assert!(matches!(report.status, Status::TimedOut(_)));
+        assert!(report.parts[0].timed_out);
+        assert_eq!(report.parts[0].answer, Solution::Unsolved);
+        assert!(!report.parts[1].timed_out);
+        assert_eq!(report.parts[1].answer, Solution::labelled("Answer", 4));
+
+        install_panic_hook();
+        let report = run_challenge(1, stub, &plan(Part::Hard, timeout), None);
+        assert!(matches!(report.status, Status::Panicked(_)));
+    }
+
+    #[test]
+    fn test_day_timeout() {
+        let plan = Plan {
+            day_timeout: Some(Duration::from_millis(20)),
+            ..plan(Part::Both, Some(Duration::from_secs(5)))
+        };
+        let report = run_challenge(1, spinner, &plan, None);
+        let Status::TimedOut(message) = report.status else {
+            panic!("Expected the day to time out, got {:?}", report.status);
+        };
+        assert_eq!(
+            message,
+            "part 1 and 2 exceeded the limit of 5s per part, 20ms per day"
+        );
+        assert!(report.parts.iter().all(|p| p.timed_out));
+        assert_eq!(report.parts[1].duration, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_run_days_reports_in_order() {
+        let days = vec![1, 2, 3, 4, 5, 6];
+        let mut seen = Vec::new();
+        run_days(
+            &days,
+            4,
+            |day| {
+                thread::sleep(Duration::from_millis(10 * (7 - day) as u64));
+                DayReport {
+                    day,
+                    title: format!("Day {day:02}"),
+                    status: Status::Ok,
+                    elapsed: Duration::ZERO,
+                    load: Duration::ZERO,
+                    load_stats: None,
+                    parse: Duration::ZERO,
+                    parse_stats: None,
+                    parts: Vec::new(),
+                }
+            },
+            |report| seen.push(report.day),
+        );
+        assert_eq!(seen, days);
+    }
+}
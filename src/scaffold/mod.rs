@@ -0,0 +1,227 @@
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+const DAY_TEMPLATE: &str = include_str!("day.rs.template");
+const YEAR_TEMPLATE: &str = include_str!("year.rs.template");
+const CHALLENGES: &str = "src/challenges";
+
+#[derive(Debug, PartialEq)]
+pub struct ScaffoldError(String);
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub fn create_day(
+    root: &Path,
+    year: usize,
+    day: usize,
+    name: &str,
+) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ScaffoldError(
+            "The day needs a non-empty --name".to_string(),
+        ));
+    }
+    let year_dir = Path::new(CHALLENGES).join(format!("year_{year}"));
+    let dir = year_dir.join(format!("day_{day:02}"));
+    if root.join(&dir).exists() {
+        return Err(ScaffoldError(format!(
+            "{} already exists, refusing to overwrite it",
+            dir.display()
+        )));
+    }
+
+    let mut files = Vec::new();
+    let year_module = year_dir.join("mod.rs");
+    let year_contents = if root.join(&year_module).exists() {
+        read(root, &year_module)?
+    } else {
+        let challenges = Path::new(CHALLENGES).join("mod.rs");
+        let contents = read(root, &challenges)?;
+        files.push((
+            challenges.clone(),
+            register(&contents, &challenges, "year_", year, 4)?,
+        ));
+        YEAR_TEMPLATE.replace("{{YEAR}}", &year.to_string())
+    };
+    files.push((
+        year_module.clone(),
+        register(&year_contents, &year_module, "day_", day, 2)?,
+    ));
+    files.push((dir.join("mod.rs"), render(year, day, name)));
+    files.push((dir.join("sample.txt"), String::new()));
+    files.push((dir.join("input.txt"), String::new()));
+
+    fs::create_dir_all(root.join(&dir)).map_err(|e| io_error(&dir, e))?;
+    for (path, contents) in &files {
+        fs::write(root.join(path), contents).map_err(|e| io_error(path, e))?;
+    }
+    Ok(files.into_iter().map(|(path, _)| path).collect())
+}
+
+fn read(root: &Path, path: &Path) -> Result<String, ScaffoldError> {
+    fs::read_to_string(root.join(path)).map_err(|e| io_error(path, e))
+}
+
+fn io_error(path: &Path, e: std::io::Error) -> ScaffoldError {
+    ScaffoldError(format!("{}: {e}", path.display()))
+}
+
+fn render(year: usize, day: usize, name: &str) -> String {
+    let name = name.replace('\\', "\\\\").replace('"', "\\\"");
+    DAY_TEMPLATE
+        .replace("{{YEAR}}", &year.to_string())
+        .replace("{{DAY}}", &format!("{day:02}"))
+        .replace("{{DAY_NUMBER}}", &day.to_string())
+        .replace("{{NAME}}", &name)
+}
+
+fn register(
+    contents: &str,
+    path: &Path,
+    prefix: &str,
+    number: usize,
+    width: usize,
+) -> Result<String, ScaffoldError> {
+    let list = format!("{}s! {{", prefix.trim_end_matches('_'));
+    let mut lines = contents.lines().collect::<Vec<&str>>();
+    let open = lines.iter().position(|l| l.trim() == list).ok_or_else(|| {
+        ScaffoldError(format!(
+            "Could not find the {list} list in {}",
+            path.display()
+        ))
+    })?;
+    let close = open
+        + lines[open..]
+            .iter()
+            .position(|l| l.trim() == "}")
+            .ok_or_else(|| {
+                ScaffoldError(format!(
+                    "The {list} list in {} is not closed",
+                    path.display()
+                ))
+            })?;
+    let registered = |l: &&str| {
+        l.trim()
+            .strip_prefix(prefix)
+            .and_then(|n| n.trim_end_matches(',').parse::<usize>().ok())
+    };
+    if lines[open..close]
+        .iter()
+        .any(|l| registered(l) == Some(number))
+    {
+        return Err(ScaffoldError(format!(
+            "{prefix}{number:0width$} is already registered in {}",
+            path.display()
+        )));
+    }
+    let index = (open + 1..close)
+        .find(|i| registered(&lines[*i]).is_some_and(|n| n > number))
+        .unwrap_or(close);
+    let line = format!("    {prefix}{number:0width$},");
+    lines.insert(index, &line);
+    Ok(lines.join("\n") + "\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::scaffold::{create_day, register, render};
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    const CHALLENGES: &str = "use x;\n\nyears! {\n    year_2025,\n}\n\npub trait Challenge {}\n";
+    const YEAR: &str = "use x;\n\ndays! {\n    day_01,\n    day_02,\n    day_05,\n}\n";
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("src/challenges/year_2025/day_01")).unwrap();
+        fs::write(root.join("src/challenges/mod.rs"), CHALLENGES).unwrap();
+        fs::write(root.join("src/challenges/year_2025/mod.rs"), YEAR).unwrap();
+        root
+    }
+
+    #[test]
+    fn test_render() {
+        let source = render(2025, 7, "A \"Quoted\" Title");
+        assert!(source.contains("const DAY: &str = \"07\";"));
+        assert!(source.contains("const NAME: &str = \"A \\\"Quoted\\\" Title\";"));
+        assert!(source.contains("    year: 2025,\n    day: 7,\n"));
+        assert!(source.contains("use crate::challenges::year_2025::day_07::{"));
+        assert!(source.contains("use crate::challenges::year_2025::sample;"));
+        assert!(!source.contains("{{"));
+    }
+
+    #[test]
+    fn test_register() {
+        let path = Path::new("mod.rs");
+        assert_eq!(
+            register(YEAR, path, "day_", 3, 2).unwrap(),
+            YEAR.replace("day_02,\n", "day_02,\n    day_03,\n")
+        );
+        assert_eq!(
+            register(YEAR, path, "day_", 12, 2).unwrap(),
+            YEAR.replace("day_05,\n", "day_05,\n    day_12,\n")
+        );
+        assert_eq!(
+            register(CHALLENGES, path, "year_", 2024, 4).unwrap(),
+            CHALLENGES.replace("    year_2025", "    year_2024,\n    year_2025")
+        );
+        assert!(register(YEAR, path, "day_", 5, 2).is_err());
+        assert!(register("pub mod day_01;\n", path, "day_", 2, 2).is_err());
+    }
+
+    #[test]
+    fn test_create_day() {
+        let root = temp_root("create");
+        let files = create_day(&root, 2025, 3, "Lobby").unwrap();
+        assert_eq!(files.len(), 4);
+        let source =
+            fs::read_to_string(root.join("src/challenges/year_2025/day_03/mod.rs")).unwrap();
+        assert!(source.contains("const NAME: &str = \"Lobby\";"));
+        assert!(root
+            .join("src/challenges/year_2025/day_03/sample.txt")
+            .exists());
+        assert!(root
+            .join("src/challenges/year_2025/day_03/input.txt")
+            .exists());
+        let year = fs::read_to_string(root.join("src/challenges/year_2025/mod.rs")).unwrap();
+        assert!(year.contains("    day_02,\n    day_03,\n    day_05,\n"));
+        assert_eq!(
+            fs::read_to_string(root.join("src/challenges/mod.rs")).unwrap(),
+            CHALLENGES
+        );
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_create_day_in_a_new_year() {
+        let root = temp_root("new-year");
+        let files = create_day(&root, 2026, 1, "Next Year").unwrap();
+        assert_eq!(files.len(), 5);
+        let challenges = fs::read_to_string(root.join("src/challenges/mod.rs")).unwrap();
+        assert!(challenges.contains("    year_2025,\n    year_2026,\n"));
+        let year = fs::read_to_string(root.join("src/challenges/year_2026/mod.rs")).unwrap();
+        assert!(year.contains("/src/challenges/year_2026/day\");"));
+        assert!(year.contains("days! {\n    day_01,\n}\n"));
+        assert!(root.join("src/challenges/year_2026/day_01/mod.rs").exists());
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_create_day_refuses_to_overwrite() {
+        let root = temp_root("overwrite");
+        assert!(create_day(&root, 2025, 1, "Existing").is_err());
+        assert!(create_day(&root, 2025, 3, " ").is_err());
+        assert!(create_day(&root, 2025, 5, "Registered").is_err());
+        assert!(!root.join("src/challenges/year_2025/day_05").exists());
+        let year = fs::read_to_string(root.join("src/challenges/year_2025/mod.rs")).unwrap();
+        assert_eq!(year, YEAR);
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
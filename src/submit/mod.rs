@@ -0,0 +1,340 @@
+use crate::helpers::root_path;
+use crate::report::{csv_field, split_csv};
+use std::fmt::{Display, Formatter};
+use std::fs;
+use std::io;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+
+pub const SUBMISSIONS_PATH: &str = root_path!("/.aoc/submissions.csv");
+
+const HEADER: &str = "timestamp,year,day,part,answer,outcome";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Guess {
+    pub timestamp: String,
+    pub year: usize,
+    pub day: usize,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Correct,
+    TooHigh,
+    TooLow,
+    Incorrect,
+    AlreadySolved,
+    RateLimited(Duration),
+    Unknown(String),
+}
+
+impl Outcome {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Outcome::Correct => "correct",
+            Outcome::TooHigh => "too high",
+            Outcome::TooLow => "too low",
+            Outcome::Incorrect => "incorrect",
+            Outcome::AlreadySolved => "already solved",
+            Outcome::RateLimited(_) => "rate limited",
+            Outcome::Unknown(_) => "unknown",
+        }
+    }
+
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "correct" => Some(Outcome::Correct),
+            "too high" => Some(Outcome::TooHigh),
+            "too low" => Some(Outcome::TooLow),
+            "incorrect" => Some(Outcome::Incorrect),
+            "already solved" => Some(Outcome::AlreadySolved),
+            "rate limited" => Some(Outcome::RateLimited(Duration::ZERO)),
+            "unknown" => Some(Outcome::Unknown(String::new())),
+            _ => None,
+        }
+    }
+
+    pub fn rejected(&self) -> bool {
+        matches!(
+            self,
+            Outcome::TooHigh | Outcome::TooLow | Outcome::Incorrect
+        )
+    }
+
+    pub fn accepted(&self) -> bool {
+        matches!(self, Outcome::Correct | Outcome::AlreadySolved)
+    }
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "That's the right answer!"),
+            Outcome::TooHigh => write!(f, "That's not the right answer, it is too high"),
+            Outcome::TooLow => write!(f, "That's not the right answer, it is too low"),
+            Outcome::Incorrect => write!(f, "That's not the right answer"),
+            Outcome::AlreadySolved => write!(f, "This part has already been solved"),
+            Outcome::RateLimited(wait) => {
+                write!(f, "Answered too recently, try again in {wait:?}")
+            }
+            Outcome::Unknown(text) => write!(f, "Unrecognised response: {text}"),
+        }
+    }
+}
+
+pub fn parse_outcome(html: &str) -> Outcome {
+    let text = article_text(html);
+    if text.contains("That's the right answer") {
+        Outcome::Correct
+    } else if text.contains("your answer is too high") {
+        Outcome::TooHigh
+    } else if text.contains("your answer is too low") {
+        Outcome::TooLow
+    } else if text.contains("That's not the right answer") {
+        Outcome::Incorrect
+    } else if text.contains("Did you already complete it?") {
+        Outcome::AlreadySolved
+    } else if text.contains("You gave an answer too recently") {
+        Outcome::RateLimited(parse_wait(&text).unwrap_or_default())
+    } else {
+        Outcome::Unknown(text)
+    }
+}
+
+fn article_text(html: &str) -> String {
+    let article = html
+        .find("<article")
+        .and_then(|start| {
+            let end = start + html[start..].find("</article>")?;
+            Some(&html[start..end])
+        })
+        .unwrap_or(html);
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    text.split_whitespace().collect::<Vec<&str>>().join(" ")
+}
+
+fn parse_wait(text: &str) -> Option<Duration> {
+    let start = text.find("You have ")? + "You have ".len();
+    let end = start + text[start..].find(" left to wait")?;
+    text[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            let split = amount.find(|c: char| !c.is_ascii_digit())?;
+            let (value, unit) = amount.split_at(split);
+            let value = value.parse::<u64>().ok()?;
+            match unit {
+                "h" => Some(value * 3600),
+                "m" => Some(value * 60),
+                "s" => Some(value),
+                _ => None,
+            }
+        })
+        .sum::<Option<u64>>()
+        .map(Duration::from_secs)
+}
+
+pub fn record(
+    path: &str,
+    timestamp: &str,
+    (year, day, part): (usize, usize, u8),
+    answer: &str,
+    outcome: &Outcome,
+) -> io::Result<()> {
+    if let Some(dir) = Path::new(path).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    let exists = Path::new(path).exists();
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)?;
+    if !exists {
+        writeln!(file, "{HEADER}")?;
+    }
+    writeln!(
+        file,
+        "{timestamp},{year},{day},{part},{},{}",
+        csv_field(answer),
+        outcome.name()
+    )
+}
+
+pub fn load(path: &str) -> io::Result<Vec<Guess>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(parse(&contents)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Vec::new()),
+        Err(e) => Err(e),
+    }
+}
+
+fn parse(contents: &str) -> Vec<Guess> {
+    contents
+        .lines()
+        .filter(|l| !l.is_empty() && *l != HEADER)
+        .filter_map(|line| {
+            let [timestamp, year, day, part, answer, outcome] = &split_csv(line)[..] else {
+                return None;
+            };
+            Some(Guess {
+                timestamp: timestamp.clone(),
+                year: year.parse().ok()?,
+                day: day.parse().ok()?,
+                part: part.parse().ok()?,
+                answer: answer.clone(),
+                outcome: Outcome::from_name(outcome)?,
+            })
+        })
+        .collect()
+}
+
+pub fn warning(
+    guesses: &[Guess],
+    (year, day, part): (usize, usize, u8),
+    answer: &str,
+) -> Option<String> {
+    let rejected = guesses
+        .iter()
+        .filter(|g| g.year == year && g.day == day && g.part == part && g.outcome.rejected())
+        .collect::<Vec<&Guess>>();
+    if let Some(guess) = rejected.iter().find(|g| g.answer == answer) {
+        return Some(format!(
+            "`{answer}` was already rejected as {} on {}",
+            guess.outcome.name(),
+            guess.timestamp
+        ));
+    }
+    let value = answer.parse::<i128>().ok()?;
+    let bound = |outcome: Outcome| {
+        rejected
+            .iter()
+            .filter(move |g| g.outcome == outcome)
+            .filter_map(|g| g.answer.parse::<i128>().ok())
+    };
+    if let Some(high) = bound(Outcome::TooHigh).min().filter(|high| value >= *high) {
+        return Some(format!(
+            "`{answer}` is not below {high}, which was too high"
+        ));
+    }
+    if let Some(low) = bound(Outcome::TooLow).max().filter(|low| value <= *low) {
+        return Some(format!("`{answer}` is not above {low}, which was too low"));
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::client::mock::serve;
+    use crate::client::Client;
+    use crate::submit::{load, parse, parse_outcome, record, warning, Outcome, HEADER};
+    use std::fs;
+    use std::time::Duration;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<!DOCTYPE html>\n<html><body><main>\n<article><p>{message}</p></article>\n</main></body></html>"
+        )
+    }
+
+    #[test]
+    fn test_parse_outcome() {
+        let cases = [
+            ("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.", Outcome::Correct),
+            ("That's not the right answer; your answer is too high.  If you're stuck, make sure you're using the full input data.", Outcome::TooHigh),
+            ("That's not the right answer; your answer is too low.", Outcome::TooLow),
+            ("That's not the right answer.  If you're stuck, <a href=\"/2025/day/3\">[Return to Day 3]</a>", Outcome::Incorrect),
+            ("You don't seem to be solving the right level.  Did you already complete it? <a href=\"/2025/day/3\">[Return to Day 3]</a>", Outcome::AlreadySolved),
+            ("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.", Outcome::RateLimited(Duration::from_secs(83))),
+            ("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 45s left to wait.", Outcome::RateLimited(Duration::from_secs(45))),
+        ];
+        for (message, outcome) in cases {
+            assert_eq!(parse_outcome(&page(message)), outcome);
+        }
+        assert_eq!(
+            parse_outcome("<p>Maintenance</p>"),
+            Outcome::Unknown("Maintenance".to_string())
+        );
+    }
+
+    #[test]
+    fn test_submit_against_mock_server() {
+        let (url, requests) = serve(vec![(
+            200,
+            &page("That's not the right answer; your answer is too low."),
+        )]);
+        let client = Client::new(&url, "secret");
+        let body = client.submit(2025, 3, 2, "17 & up").unwrap();
+        assert_eq!(parse_outcome(&body), Outcome::TooLow);
+        let request = requests.recv().unwrap();
+        assert!(request.starts_with("POST /2025/day/3/answer HTTP/1.1\r\n"));
+        assert!(request.contains("cookie: session=secret\r\n"));
+        assert!(request.ends_with("\r\n\r\nlevel=2&answer=17+%26+up"));
+    }
+
+    #[test]
+    fn test_record() {
+        let path = std::env::temp_dir().join(format!("aoc-submissions-{}", std::process::id()));
+        let path = path.join("submissions.csv").display().to_string();
+        record(&path, "t1", (2025, 3, 1), "17", &Outcome::TooLow).unwrap();
+        record(&path, "t2", (2025, 3, 1), "a,b", &Outcome::Correct).unwrap();
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            format!("{HEADER}\nt1,2025,3,1,17,too low\nt2,2025,3,1,\"a,b\",correct\n")
+        );
+        let guesses = load(&path).unwrap();
+        assert_eq!(guesses.len(), 2);
+        assert_eq!(guesses[1].answer, "a,b");
+        assert_eq!(guesses[1].outcome, Outcome::Correct);
+        fs::remove_dir_all(std::path::Path::new(&path).parent().unwrap()).unwrap();
+        assert!(load(&path).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_warning() {
+        let guesses = parse(
+            &[
+                HEADER,
+                "t1,2025,3,1,100,too low",
+                "t2,2025,3,1,500,too high",
+                "t3,2025,3,1,300,incorrect",
+                "t4,2025,3,1,400,rate limited",
+                "t5,2025,3,2,900,too high",
+                "t6,2024,3,1,200,too high",
+            ]
+            .join("\n"),
+        );
+        let check = |answer| warning(&guesses, (2025, 3, 1), answer);
+        assert_eq!(
+            check("300").unwrap(),
+            "`300` was already rejected as incorrect on t3"
+        );
+        assert_eq!(
+            check("500").unwrap(),
+            "`500` was already rejected as too high on t2"
+        );
+        assert_eq!(
+            check("501").unwrap(),
+            "`501` is not below 500, which was too high"
+        );
+        assert_eq!(
+            check("99").unwrap(),
+            "`99` is not above 100, which was too low"
+        );
+        assert_eq!(check("200"), None);
+        assert_eq!(check("400"), None);
+        assert_eq!(check("abc"), None);
+        assert_eq!(warning(&guesses, (2025, 3, 2), "300"), None);
+    }
+}
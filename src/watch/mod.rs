@@ -0,0 +1,233 @@
+use crate::helpers::{day_dir, ROOT};
+use crate::report::split_csv;
+use std::collections::BTreeMap;
+use std::fs;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime};
+
+const POLL_INTERVAL: Duration = Duration::from_millis(200);
+const DEBOUNCE: Duration = Duration::from_millis(400);
+const WATCHED: [&str; 5] = [
+    "mod.rs",
+    "input.txt",
+    "input.txt.enc",
+    "sample.txt",
+    "sample.expected",
+];
+
+type Fingerprint = Vec<Option<(SystemTime, u64)>>;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartResult {
+    pub answer: String,
+    pub verdict: String,
+}
+
+pub struct Debounce {
+    seen: Fingerprint,
+    changed_at: Option<Instant>,
+}
+
+impl Debounce {
+    pub fn new(seen: Fingerprint) -> Self {
+        Debounce {
+            seen,
+            changed_at: None,
+        }
+    }
+
+    pub fn settled(&mut self, current: Fingerprint, now: Instant) -> bool {
+        if current != self.seen {
+            self.seen = current;
+            self.changed_at = Some(now);
+            return false;
+        }
+        match self.changed_at {
+            Some(changed_at) if now.duration_since(changed_at) >= DEBOUNCE => {
+                self.changed_at = None;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+pub fn watch(year: usize, day: usize) -> ! {
+    let dir = day_dir(year, day);
+    println!("Watching {dir} for changes, press Ctrl-C to stop");
+    let mut previous = rerun(year, day, &BTreeMap::new());
+    let mut debounce = Debounce::new(fingerprint(&dir));
+    loop {
+        thread::sleep(POLL_INTERVAL);
+        if debounce.settled(fingerprint(&dir), Instant::now()) {
+            previous = rerun(year, day, &previous);
+        }
+    }
+}
+
+fn fingerprint(dir: &str) -> Fingerprint {
+    WATCHED
+        .iter()
+        .map(|file| {
+            let metadata = fs::metadata(format!("{dir}/{file}")).ok()?;
+            Some((metadata.modified().ok()?, metadata.len()))
+        })
+        .collect()
+}
+
+fn rerun(year: usize, day: usize, previous: &BTreeMap<u8, PartResult>) -> BTreeMap<u8, PartResult> {
+    println!();
+    println!("==== {year} Day {day:02} ====");
+    let tests = Command::new("cargo")
+        .args([
+            "test",
+            "--quiet",
+            "--",
+            &format!("challenges::year_{year}::day_{day:02}::"),
+        ])
+        .current_dir(ROOT)
+        .status();
+    match tests {
+        Ok(status) if status.success() => println!(">> Sample tests passed"),
+        Ok(_) => println!(">> Sample tests FAILED"),
+        Err(e) => println!(">> Could not run cargo test: {e}"),
+    }
+
+    let output = Command::new("cargo")
+        .args(["run", "--release", "--quiet", "--", &day.to_string()])
+        .args(["--year", &year.to_string()])
+        .args(["--format", "csv"])
+        .current_dir(ROOT)
+        .stderr(Stdio::inherit())
+        .output();
+    let current = match output {
+        Ok(output) if output.status.success() || output.status.code() == Some(1) => {
+            parse_results(&String::from_utf8_lossy(&output.stdout))
+        }
+        Ok(_) => {
+            println!(">> The solution did not build or run, keeping the previous answers");
+            return previous.clone();
+        }
+        Err(e) => {
+            println!(">> Could not run cargo run: {e}");
+            return previous.clone();
+        }
+    };
+    diff(previous, &current)
+        .iter()
+        .for_each(|line| println!(">> {line}"));
+    current
+}
+
+fn parse_results(csv: &str) -> BTreeMap<u8, PartResult> {
+    let mut lines = csv.lines().map(split_csv);
+    let Some(header) = lines.next() else {
+        return BTreeMap::new();
+    };
+    let Some(record) = lines.next() else {
+        return BTreeMap::new();
+    };
+    let field = |name: &str| {
+        header
+            .iter()
+            .position(|h| h == name)
+            .and_then(|i| record.get(i))
+            .filter(|value| !value.is_empty())
+            .cloned()
+    };
+    (1..=2)
+        .filter_map(|part| {
+            field(&format!("part{part}_duration_ns"))?;
+            let answer = field(&format!("part{part}_answer")).unwrap_or("No Result".to_string());
+            let verdict = field(&format!("part{part}_verdict")).unwrap_or_default();
+            Some((part, PartResult { answer, verdict }))
+        })
+        .collect()
+}
+
+fn diff(previous: &BTreeMap<u8, PartResult>, current: &BTreeMap<u8, PartResult>) -> Vec<String> {
+    current
+        .iter()
+        .map(|(part, result)| {
+            let verdict = match result.verdict.as_str() {
+                "" => String::new(),
+                verdict => format!(" [{verdict}]"),
+            };
+            match previous.get(part) {
+                None => format!("Part {part}: {}{verdict}", result.answer),
+                Some(before) if before.answer == result.answer => {
+                    format!("Part {part}: {}{verdict} (unchanged)", result.answer)
+                }
+                Some(before) => format!(
+                    "Part {part}: {} -> {}{verdict}",
+                    before.answer, result.answer
+                ),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::watch::{diff, parse_results, Debounce, PartResult, DEBOUNCE};
+    use std::collections::BTreeMap;
+    use std::time::{Duration, Instant, SystemTime};
+
+    fn result(answer: &str, verdict: &str) -> PartResult {
+        PartResult {
+            answer: answer.to_string(),
+            verdict: verdict.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_debounce_waits_for_changes_to_settle() {
+        let start = Instant::now();
+        let saved = |secs| {
+            vec![Some((
+                SystemTime::UNIX_EPOCH + Duration::from_secs(secs),
+                1,
+            ))]
+        };
+        let mut debounce = Debounce::new(saved(1));
+        assert!(!debounce.settled(saved(1), start));
+        assert!(!debounce.settled(saved(2), start));
+        assert!(!debounce.settled(saved(3), start + DEBOUNCE / 2));
+        assert!(!debounce.settled(saved(3), start + DEBOUNCE));
+        assert!(debounce.settled(saved(3), start + DEBOUNCE * 2));
+        assert!(!debounce.settled(saved(3), start + DEBOUNCE * 3));
+    }
+
+    #[test]
+    fn test_parse_results() {
+        let csv = "day,title,status,message,load_ns,parse_ns,\
+part1_duration_ns,part1_answer,part1_verdict,part2_duration_ns,part2_answer,part2_verdict\n\
+1,Day 01 - Secret Entrance,ok,,5,10,20,3,pass,30,,\n";
+        let results = parse_results(csv);
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[&1], result("3", "pass"));
+        assert_eq!(results[&2], result("No Result", ""));
+        assert!(parse_results("").is_empty());
+    }
+
+    #[test]
+    fn test_diff() {
+        let previous = BTreeMap::from([(1, result("Zeros: 3", "")), (2, result("Zeros: 6", ""))]);
+        let current = BTreeMap::from([
+            (1, result("Zeros: 3", "pass")),
+            (2, result("Zeros: 7", "fail")),
+        ]);
+        assert_eq!(
+            diff(&previous, &current),
+            vec![
+                "Part 1: Zeros: 3 [pass] (unchanged)",
+                "Part 2: Zeros: 6 -> Zeros: 7 [fail]"
+            ]
+        );
+        assert_eq!(
+            diff(&BTreeMap::new(), &current)[0],
+            "Part 1: Zeros: 3 [pass]"
+        );
+    }
+}